@@ -11,17 +11,35 @@ struct InequalityConstr {
     rhs: Box<Expr>,
 }
 
-impl Parse for InequalityConstr {
-    fn parse(input: ParseStream) -> Result<Self> {
+impl InequalityConstr {
+    fn from_binary(cmpexpr: syn::ExprBinary) -> Result<Self> {
         use syn::BinOp::*;
 
-        let cmpexpr: syn::ExprBinary = input.parse()?;
         let sense = match cmpexpr.op {
             Eq(..) => quote! { grb::ConstrSense::Equal },
             Le(..) => quote! { grb::ConstrSense::Less },
             Ge(..) => quote! { grb::ConstrSense::Greater },
-            Lt(..) | Gt(..) | Ne(..) => {
-                return Err(Error::new_spanned(cmpexpr.op, "expected >=, <= or =="));
+            Lt(..) => {
+                return Err(Error::new_spanned(
+                    cmpexpr.op,
+                    "strict inequalities are unsupported, expected >=, <= or ==; \
+                     for integer expressions, use `lhs <= rhs - 1` instead of `lhs < rhs`",
+                ));
+            }
+            Gt(..) => {
+                return Err(Error::new_spanned(
+                    cmpexpr.op,
+                    "strict inequalities are unsupported, expected >=, <= or ==; \
+                     for integer expressions, use `lhs >= rhs + 1` instead of `lhs > rhs`",
+                ));
+            }
+            Ne(..) => {
+                return Err(Error::new_spanned(
+                    cmpexpr.op,
+                    "`!=` constraints are unsupported, expected >=, <= or ==; \
+                     for integer expressions, use a binary variable to choose between \
+                     `lhs <= rhs - 1` and `lhs >= rhs + 1`",
+                ));
             }
             _ => {
                 return Err(Error::new_spanned(
@@ -41,6 +59,8 @@ impl Parse for InequalityConstr {
 
 impl ToTokens for InequalityConstr {
     fn to_tokens(&self, tokens: &mut TokenStream2) {
+        // The conversions are spanned to their operands, so that if an operand cannot be converted into an
+        // `Expr`, the error points at the operand rather than the whole macro invocation.
         let lhs = self.lhs.as_ref();
         let lhs = quote_spanned! { lhs.span()=> grb::Expr::from(#lhs) };
         let rhs = self.rhs.as_ref();
@@ -57,6 +77,103 @@ impl ToTokens for InequalityConstr {
     }
 }
 
+/// Strip any parentheses surrounding an expression
+fn unparen(expr: &Expr) -> &Expr {
+    match expr {
+        Expr::Paren(e) => unparen(&e.expr),
+        e => e,
+    }
+}
+
+/// Is the expression a comparison, e.g. `x <= 1`?  Unsupported comparisons like `x < 1` are included so they
+/// can produce the usual error messages.
+fn is_comparison(expr: &Expr) -> bool {
+    use syn::BinOp::*;
+    match unparen(expr) {
+        Expr::Binary(e) => matches!(e.op, Eq(..) | Le(..) | Ge(..) | Lt(..) | Gt(..) | Ne(..)),
+        _ => false,
+    }
+}
+
+struct IndicatorConstr {
+    binvar: Box<Expr>,
+    binval: bool,
+    constr: InequalityConstr,
+}
+
+impl IndicatorConstr {
+    /// Try to interpret a binary expression as an indicator constraint `BINVAR == VAL >> (CONSTR)`.  Because `>>`
+    /// binds more tightly than `==`, this is parsed as `BINVAR == (VAL >> (CONSTR))`; the form
+    /// `(BINVAR == VAL) >> (CONSTR)` is also accepted.  Returns the expression unchanged if it is not an implication.
+    fn from_binary(expr: syn::ExprBinary) -> Result<std::result::Result<Self, syn::ExprBinary>> {
+        use syn::BinOp::*;
+
+        let (binvar, binval, constr) = match expr.op {
+            Shr(..) => match unparen(&expr.left) {
+                Expr::Binary(cond) if matches!(cond.op, Eq(..)) => {
+                    (cond.left.clone(), cond.right.as_ref(), expr.right.as_ref())
+                }
+                _ => return Err(Error::new_spanned(
+                    &expr.left,
+                    "expected an indicator condition `BINVAR == 0` or `BINVAR == 1` before `>>`",
+                )),
+            },
+            Eq(..) => match expr.right.as_ref() {
+                Expr::Binary(imp) if matches!(imp.op, Shr(..)) && is_comparison(&imp.right) => {
+                    (expr.left.clone(), imp.left.as_ref(), imp.right.as_ref())
+                }
+                _ => return Ok(Err(expr)),
+            },
+            _ => return Ok(Err(expr)),
+        };
+
+        let binval = match unparen(binval) {
+            Expr::Lit(syn::ExprLit {
+                lit: syn::Lit::Int(i),
+                ..
+            }) if i.base10_digits() == "0" || i.base10_digits() == "1" => i.base10_digits() == "1",
+            _ => {
+                return Err(Error::new_spanned(
+                    binval,
+                    "the value of an indicator variable must be the literal 0 or 1",
+                ))
+            }
+        };
+
+        let constr = match unparen(constr) {
+            Expr::Binary(e) if is_comparison(constr) => InequalityConstr::from_binary(e.clone())?,
+            _ => {
+                return Err(Error::new_spanned(
+                    constr,
+                    "expected a linear constraint `(LHS CMP RHS)` after `>>`",
+                ))
+            }
+        };
+
+        Ok(Ok(IndicatorConstr {
+            binvar,
+            binval,
+            constr,
+        }))
+    }
+}
+
+impl ToTokens for IndicatorConstr {
+    fn to_tokens(&self, tokens: &mut TokenStream2) {
+        let binvar = &self.binvar;
+        let binval = self.binval;
+        let constr = &self.constr;
+        let ts = quote! {
+          grb::constr::IndicatorExpr{
+            binvar: #binvar,
+            binval: #binval,
+            constr: #constr,
+          }
+        };
+        ts.to_tokens(tokens);
+    }
+}
+
 #[derive(Default, Clone)]
 struct GrbRangeExpr {
     lb: Option<Box<syn::Expr>>,
@@ -64,16 +181,18 @@ struct GrbRangeExpr {
 }
 
 impl GrbRangeExpr {
+    // The bound is parenthesised before the cast, since `as` binds more tightly than binary operators:
+    // `0..n + 1` should give `(n + 1) as f64`, not `n + (1 as f64)`.
     pub fn ub_to_tokens(&self) -> TokenStream2 {
         match self.ub {
-            Some(ref x) => quote_spanned! { x.span()=>  #x as f64},
+            Some(ref x) => quote_spanned! { x.span()=> (#x) as f64 },
             None => quote! { grb::INFINITY },
         }
     }
 
     pub fn lb_to_tokens(&self) -> TokenStream2 {
         match self.lb {
-            Some(ref x) => quote_spanned! { x.span()=> #x as f64},
+            Some(ref x) => quote_spanned! { x.span()=> (#x) as f64 },
             None => quote! { -grb::INFINITY },
         }
     }
@@ -81,16 +200,8 @@ impl GrbRangeExpr {
 
 impl Parse for GrbRangeExpr {
     fn parse(input: ParseStream) -> Result<Self> {
+        // Bounds and range constraints are always inclusive, so `LB..UB` and `LB..=UB` mean the same thing.
         let expr: syn::ExprRange = input.parse()?;
-        match expr.limits {
-            syn::RangeLimits::HalfOpen(..) => {}
-            syn::RangeLimits::Closed(dde) => {
-                return Err(Error::new_spanned(
-                    dde,
-                    "Use '..' for bounds and range constraints",
-                ))
-            }
-        }
         Ok(GrbRangeExpr {
             lb: expr.from,
             ub: expr.to,
@@ -135,6 +246,7 @@ impl ToTokens for RangeConstr {
 enum ConstrExpr {
     Inequality(InequalityConstr),
     Range(RangeConstr),
+    Indicator(IndicatorConstr),
 }
 
 impl Parse for ConstrExpr {
@@ -160,9 +272,10 @@ impl Parse for ConstrExpr {
         if in_found {
             input.parse::<RangeConstr>().map(ConstrExpr::Range)
         } else {
-            input
-                .parse::<InequalityConstr>()
-                .map(ConstrExpr::Inequality)
+            match IndicatorConstr::from_binary(input.parse()?)? {
+                Ok(e) => Ok(ConstrExpr::Indicator(e)),
+                Err(e) => InequalityConstr::from_binary(e).map(ConstrExpr::Inequality),
+            }
         }
     }
 }
@@ -172,6 +285,7 @@ impl ToTokens for ConstrExpr {
         match self {
             ConstrExpr::Inequality(e) => e.to_tokens(tokens),
             ConstrExpr::Range(e) => e.to_tokens(tokens),
+            ConstrExpr::Indicator(e) => e.to_tokens(tokens),
         }
     }
 }
@@ -249,11 +363,13 @@ macro_rules! impl_optional_arg {
 impl_optional_arg!(VarName, syn::Expr, "name", quote! { "" });
 impl_optional_arg!(VarObj, syn::Expr, "obj", quote! { 0.0 });
 impl_optional_arg!(VarBounds, GrbRangeExpr, "bounds");
+impl_optional_arg!(VarStart, syn::Expr, "start");
 
 struct OptArgs {
     name: VarName,
     obj: VarObj,
     bounds: VarBounds,
+    start: VarStart,
 }
 
 impl OptArgs {
@@ -265,7 +381,33 @@ impl OptArgs {
             None => (quote! { 0.0f64 }, quote! { grb::INFINITY }),
         };
 
-        quote! { #model.add_var(#name, #vtype, #obj as f64, #lb, #ub, std::iter::empty() ) }
+        match self.start.0 {
+            None => {
+                quote! { #model.add_var(#name, #vtype, #obj as f64, #lb, #ub, std::iter::empty() ) }
+            }
+            Some(ref start) => {
+                let start = quote_spanned! { start.span()=> #start as f64 };
+                // BorrowMut (rather than `&mut #model`) so this also works when `#model` is an immutable
+                // binding of type `&mut Model`.
+                quote! {
+                  {
+                    use std::borrow::BorrowMut as _;
+                    let __grb_model: &mut grb::Model = #model.borrow_mut();
+                    let __grb_lb: f64 = #lb;
+                    let __grb_ub: f64 = #ub;
+                    let __grb_start: f64 = #start;
+                    if __grb_lb <= __grb_start && __grb_start <= __grb_ub {
+                      __grb_model.add_var(#name, #vtype, #obj as f64, __grb_lb, __grb_ub, std::iter::empty())
+                        .and_then(|v| __grb_model.set_start_deferred(v, __grb_start).map(|()| v))
+                    } else {
+                      Err(grb::Error::InvalidArgument(format!(
+                        "start value {} is outside the variable bounds [{}, {}]", __grb_start, __grb_lb, __grb_ub
+                      )))
+                    }
+                  }
+                }
+            }
+        }
     }
 }
 
@@ -274,6 +416,7 @@ impl Parse for OptArgs {
         let mut name = VarName(None);
         let mut bounds = VarBounds(None);
         let mut obj = VarObj(None);
+        let mut start = VarStart(None);
 
         while !input.is_empty() {
             let comma = input.parse::<Token![,]>()?;
@@ -287,7 +430,8 @@ impl Parse for OptArgs {
 
             if !(name.match_parse(&optname, &input)?
                 || obj.match_parse(&optname, &input)?
-                || bounds.match_parse(&optname, &input)?)
+                || bounds.match_parse(&optname, &input)?
+                || start.match_parse(&optname, &input)?)
             {
                 return Err(Error::new_spanned(
                     &optname,
@@ -295,7 +439,12 @@ impl Parse for OptArgs {
                 ));
             };
         }
-        Ok(OptArgs { name, obj, bounds })
+        Ok(OptArgs {
+            name,
+            obj,
+            bounds,
+            start,
+        })
     }
 }
 
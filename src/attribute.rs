@@ -3,9 +3,10 @@
 //!
 //! Setting or querying the wrong attribute for an object will result in an [`Error::FromAPI`](crate::Error::FromAPI).
 
-use std::convert::TryInto;
-use std::ffi::CString;
+use std::convert::{TryFrom, TryInto};
+use std::ffi::{CStr, CString};
 use std::iter::IntoIterator;
+use std::marker::PhantomData;
 #[allow(unused_imports)] // false positive - used in macros
 use std::ptr::{null, null_mut};
 
@@ -15,7 +16,7 @@ use crate::ffi;
 use crate::ffi::{c_char, c_int};
 use crate::model_object::*;
 use crate::util::{copy_c_str, AsPtr};
-use crate::{ConstrSense, Model, ModelSense, Result, Status, VarType};
+use crate::{ConstrSense, Error, GenConstrType, Model, ModelSense, Result, Status, VarType};
 
 #[allow(missing_docs)]
 mod attr_enums {
@@ -181,10 +182,7 @@ macro_rules! impl_obj_get_custom {
                 ))?;
             }
 
-            let vals = vals
-                .into_iter()
-                .map(|ch| (ch as c_char).try_into().unwrap())
-                .collect();
+            let vals = vals.into_iter().map(|v| v.try_into().unwrap()).collect();
             Ok(vals)
         }
     };
@@ -284,6 +282,23 @@ impl ObjAttrGet<Constr, ConstrSense> for ConstrSenseAttr {
     impl_obj_get_custom! { ConstrSense, 0i8, ffi::GRBgetcharattrelement, ffi::GRBgetcharattrlist}
 }
 
+impl ObjAttrGet<GenConstr, GenConstrType> for GenConstrGenConstrTypeAttr {
+    impl_obj_get_custom! { GenConstrType, 0i32, ffi::GRBgetintattrelement, ffi::GRBgetintattrlist}
+}
+
+/// Copy a string attribute value returned by Gurobi.  A null pointer is reported as [`Error::DataNotAvailable`]
+/// naming the attribute and, for object attributes, the index of the object.
+unsafe fn copy_str_attr(s: *const c_char, attr: &CStr, idx: Option<i32>) -> Result<String> {
+    if s.is_null() {
+        let msg = match idx {
+            Some(i) => format!("Gurobi returned a null string for attribute {attr:?} at index {i}"),
+            None => format!("Gurobi returned a null string for attribute {attr:?}"),
+        };
+        return Err(Error::DataNotAvailable(msg));
+    }
+    Ok(copy_c_str(s))
+}
+
 /// From the Gurobi manual regarding string attributes:
 ///
 /// Note that all interface routines that return string-valued attributes are returning pointers into internal
@@ -303,10 +318,7 @@ where
                 idx,
                 &mut s,
             ))?;
-            if s.is_null() {
-                return Ok(String::new());
-            }
-            Ok(copy_c_str(s))
+            copy_str_attr(s, self.as_cstr(), Some(idx))
         }
     }
 
@@ -328,8 +340,11 @@ where
                 cstrings.as_mut_ptr(),
             ))?;
 
-            let strings = cstrings.into_iter().map(|s| copy_c_str(s)).collect();
-            Ok(strings)
+            cstrings
+                .into_iter()
+                .zip(inds)
+                .map(|(s, i)| copy_str_attr(s, self.as_cstr(), Some(i)))
+                .collect()
         }
     }
 }
@@ -432,10 +447,7 @@ impl ModelAttrGet<String> for ModelStrAttr {
                 self.as_cstr().as_ptr(),
                 &mut val,
             ))?;
-            if val.is_null() {
-                return Ok(String::new());
-            }
-            Ok(copy_c_str(val))
+            copy_str_attr(val, self.as_cstr(), None)
         }
     }
 }
@@ -499,6 +511,181 @@ impl ModelAttrGet<Status> for ModelStatusAttr {
     }
 }
 
+/// Support for querying attributes by name, including attributes not yet known to this crate.
+///
+/// This is the attribute equivalent of [`Parameter`](crate::parameter::Parameter).  Use an instance of
+/// this type with the `Model::get_raw_*_attr` methods, such as [`Model::get_raw_int_attr`] or
+/// [`Model::get_raw_dbl_attr_element`].  [`Model::get_attr_info`] can be used to find the type of an attribute.
+///
+/// # Example
+/// ```
+/// use grb::prelude::*;
+/// use grb::attribute::RawAttr;
+///
+/// let mut m = Model::new("model")?;
+/// let x = add_ctsvar!(m, name: "x", bounds: ..5)?;
+/// m.update()?;
+///
+/// assert_eq!(m.get_raw_int_attr(&RawAttr::new("NumVars")?)?, 1);
+/// assert_eq!(m.get_raw_dbl_attr_element(&RawAttr::new("UB")?, &x)?, 5.0);
+/// assert_eq!(m.get_raw_str_attr_element(&RawAttr::new("VarName")?, &x)?, "x");
+///
+/// // Wrong type results in a FromAPI error
+/// assert!(matches!(m.get_raw_str_attr(&RawAttr::new("NumVars")?), Err(grb::Error::FromAPI(_, _))));
+/// # Ok::<(), grb::Error>(())
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RawAttr {
+    name: CString,
+}
+
+impl RawAttr {
+    /// Declare a new attribute.
+    ///
+    /// # Errors
+    /// Will return an [`Error::NulError`] if the string given cannot be converted into a
+    /// C-style string.
+    pub fn new(name: impl Into<Vec<u8>>) -> Result<RawAttr> {
+        Ok(RawAttr {
+            name: CString::new(name)?,
+        })
+    }
+
+    /// View this attribute as an attribute of model objects of type `O`.
+    pub(crate) fn for_obj<O>(&self) -> RawObjAttr<'_, O> {
+        RawObjAttr(self, PhantomData)
+    }
+}
+
+impl AsCStr for RawAttr {
+    fn as_cstr(&self) -> &CStr {
+        &self.name
+    }
+}
+
+macro_rules! impl_raw_model_attr_get {
+    ($t:ty, $default:expr, $get:path) => {
+        impl ModelAttrGet<$t> for &RawAttr {
+            fn get(&self, model: &Model) -> Result<$t> {
+                let mut val = $default;
+                unsafe {
+                    model.check_apicall($get(
+                        model.as_mut_ptr(),
+                        self.as_cstr().as_ptr(),
+                        &mut val,
+                    ))?
+                }
+                Ok(val)
+            }
+        }
+    };
+}
+
+impl_raw_model_attr_get! { i32, i32::MIN, ffi::GRBgetintattr }
+impl_raw_model_attr_get! { f64, f64::NAN, ffi::GRBgetdblattr }
+
+impl ModelAttrGet<String> for &RawAttr {
+    fn get(&self, model: &Model) -> Result<String> {
+        unsafe {
+            let mut val: *const c_char = null_mut();
+            model.check_apicall(ffi::GRBgetstrattr(
+                model.as_mut_ptr(),
+                self.as_cstr().as_ptr(),
+                &mut val,
+            ))?;
+            copy_str_attr(val, self.as_cstr(), None)
+        }
+    }
+}
+
+/// A [`RawAttr`] for objects of type `O`, so the generic object attribute implementations can be reused.
+pub(crate) struct RawObjAttr<'a, O>(&'a RawAttr, PhantomData<O>);
+
+impl<O> AsCStr for RawObjAttr<'_, O> {
+    fn as_cstr(&self) -> &CStr {
+        self.0.as_cstr()
+    }
+}
+
+impl<O> IntAttr for RawObjAttr<'_, O> {}
+impl<O> DoubleAttr for RawObjAttr<'_, O> {}
+impl<O> StrAttr for RawObjAttr<'_, O> {}
+
+impl<O: ModelObject> ObjAttr for RawObjAttr<'_, O> {
+    type Obj = O;
+}
+
+/// The data type of an attribute, see [`AttrInfo`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum AttrDataType {
+    /// A `char` attribute, such as `VType` or `Sense`
+    Char,
+    /// An integer attribute
+    Int,
+    /// A double attribute
+    Double,
+    /// A string attribute
+    String,
+}
+
+impl TryFrom<i32> for AttrDataType {
+    type Error = Error;
+    fn try_from(val: i32) -> Result<AttrDataType> {
+        match val {
+            0 => Ok(AttrDataType::Char),
+            1 => Ok(AttrDataType::Int),
+            2 => Ok(AttrDataType::Double),
+            3 => Ok(AttrDataType::String),
+            _ => Err(Error::NotYetSupported(format!("attribute data type {val}"))),
+        }
+    }
+}
+
+/// The kind of object an attribute belongs to, see [`AttrInfo`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum AttrObjType {
+    /// A model attribute
+    Model,
+    /// A [`Var`] attribute
+    Var,
+    /// A [`Constr`] attribute
+    Constr,
+    /// An [`SOS`] attribute
+    SOS,
+    /// A [`QConstr`] attribute
+    QConstr,
+    /// A [`GenConstr`] attribute
+    GenConstr,
+}
+
+impl TryFrom<i32> for AttrObjType {
+    type Error = Error;
+    fn try_from(val: i32) -> Result<AttrObjType> {
+        match val {
+            0 => Ok(AttrObjType::Model),
+            1 => Ok(AttrObjType::Var),
+            2 => Ok(AttrObjType::Constr),
+            3 => Ok(AttrObjType::SOS),
+            4 => Ok(AttrObjType::QConstr),
+            5 => Ok(AttrObjType::GenConstr),
+            _ => Err(Error::NotYetSupported(format!(
+                "attribute object type {val}"
+            ))),
+        }
+    }
+}
+
+/// Information about an attribute, as returned by [`Model::get_attr_info`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct AttrInfo {
+    /// The data type of the attribute
+    pub datatype: AttrDataType,
+    /// The kind of object the attribute belongs to
+    pub attrtype: AttrObjType,
+    /// Whether the attribute can be modified
+    pub settable: bool,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -574,10 +761,7 @@ mod tests {
                     self.as_cstr().as_ptr(),
                     &mut val,
                 ))?;
-                if val.is_null() {
-                    return Ok(String::new());
-                }
-                Ok(copy_c_str(val))
+                copy_str_attr(val, self.as_cstr(), None)
             }
         }
     }
@@ -666,4 +850,34 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn str_attr_null() {
+        let name = CStr::from_bytes_with_nul(b"VarName\0").unwrap();
+        let err = unsafe { copy_str_attr(std::ptr::null(), name, Some(3)) }.unwrap_err();
+        assert_eq!(
+            err,
+            Error::DataNotAvailable(
+                "Gurobi returned a null string for attribute \"VarName\" at index 3".to_string()
+            )
+        );
+        let err = unsafe { copy_str_attr(std::ptr::null(), name, None) }.unwrap_err();
+        assert!(matches!(err, Error::DataNotAvailable(_)));
+        let s = unsafe { copy_str_attr(name.as_ptr(), name, Some(3)) };
+        assert_eq!(s, Ok("VarName".to_string()));
+    }
+
+    #[test]
+    fn str_attr_batch_before_update() -> anyhow::Result<()> {
+        let mut model = Model::new("test")?;
+        let x = grb::add_ctsvar!(model, name: "x")?;
+        let y = grb::add_ctsvar!(model, name: "y")?;
+
+        let err = model.get_obj_attr_batch(attr::VarName, [x, y]).unwrap_err();
+        assert_eq!(err, crate::Error::ModelObjectPending);
+
+        model.update()?;
+        assert_eq!(model.get_obj_attr_batch(attr::VarName, [x, y])?, ["x", "y"]);
+        Ok(())
+    }
 }
@@ -0,0 +1,142 @@
+//! Batch optimization, available with the `batch` feature.
+//!
+//! A batch is a model which is submitted to a Cluster Manager (or Instant Cloud) with [`Model::submit_batch`],
+//! and solved in the background.  The batch can later be queried and its solution retrieved using only its
+//! [`BatchId`], even from a different process.  See the
+//! [manual](https://docs.gurobi.com/projects/optimizer/en/current/features/batchoptimization.html) for details.
+use std::convert::TryFrom;
+use std::ffi::CString;
+use std::fmt;
+use std::ptr::null_mut;
+
+use crate::constants::GRB_MAX_STRLEN;
+use crate::ffi;
+use crate::util::{copy_c_str, AsPtr};
+use crate::{Env, Error, Model, Result};
+
+/// The unique identifier of a batch, returned by [`Model::submit_batch`].
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct BatchId(String);
+
+impl BatchId {
+    /// Wrap an existing batch ID, for example one created by another process.
+    pub fn new(id: impl Into<String>) -> BatchId {
+        BatchId(id.into())
+    }
+
+    /// The batch ID as a string.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl fmt::Display for BatchId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+/// Status of a batch (the `BatchStatus` batch attribute)
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+#[repr(i32)]
+pub enum BatchStatus {
+    /// The batch has been created, but not yet submitted
+    Created = 1,
+    /// The batch has been submitted and is queued or being solved
+    Submitted,
+    /// The batch was aborted
+    Aborted,
+    /// The batch failed
+    Failed,
+    /// The batch was solved, and its solution can be retrieved
+    Completed,
+}
+
+impl TryFrom<i32> for BatchStatus {
+    type Error = String;
+    fn try_from(val: i32) -> std::result::Result<BatchStatus, String> {
+        match val {
+            1 => Ok(BatchStatus::Created),
+            2 => Ok(BatchStatus::Submitted),
+            3 => Ok(BatchStatus::Aborted),
+            4 => Ok(BatchStatus::Failed),
+            5 => Ok(BatchStatus::Completed),
+            _ => Err(format!(
+                "Invalid BatchStatus value {val}, should be in 1..=5"
+            )),
+        }
+    }
+}
+
+/// Owned handle to a `GRBbatch` object, freed on drop.
+struct Batch {
+    ptr: *mut ffi::GRBbatch,
+}
+
+impl Batch {
+    fn new(env: &Env, id: &BatchId) -> Result<Batch> {
+        let id = CString::new(id.as_str())?;
+        let mut ptr = null_mut();
+        env.check_apicall(unsafe { ffi::GRBgetbatch(env.as_mut_ptr(), id.as_ptr(), &mut ptr) })?;
+        Ok(Batch { ptr })
+    }
+}
+
+impl Drop for Batch {
+    fn drop(&mut self) {
+        unsafe { ffi::GRBfreebatch(self.ptr) };
+    }
+}
+
+impl Model {
+    /// Submit the model for batch optimization, returning the ID of the new batch.  The model is
+    /// updated before it is submitted.  The model's environment must have been started with the
+    /// `CSBatchMode` parameter set to `1`.
+    ///
+    /// # Errors
+    /// Returns [`Error::FromAPI`] if the batch could not be submitted, for example if the environment
+    /// is not connected to a Cluster Manager.
+    #[doc(alias = "optimize_batch")]
+    pub fn submit_batch(&mut self) -> Result<BatchId> {
+        self.update()?;
+        let mut buf = [0 as ffi::c_char; GRB_MAX_STRLEN + 1];
+        self.check_apicall(unsafe { ffi::GRBoptimizebatch(self.as_mut_ptr(), buf.as_mut_ptr()) })?;
+        Ok(BatchId(unsafe { copy_c_str(buf.as_ptr()) }))
+    }
+
+    /// Query the current status of a batch using this model's environment.  See [`Env::batch_status`].
+    pub fn batch_status(&self, id: &BatchId) -> Result<BatchStatus> {
+        self.get_env().batch_status(id)
+    }
+}
+
+impl Env {
+    /// Query the current status of a batch.
+    ///
+    /// # Errors
+    /// - [`Error::FromAPI`] if the batch cannot be found or queried.
+    /// - [`Error::NotYetSupported`] if Gurobi reports a batch status unknown to this crate.
+    pub fn batch_status(&self, id: &BatchId) -> Result<BatchStatus> {
+        let batch = Batch::new(self, id)?;
+        let mut status = 0;
+        self.check_apicall(unsafe {
+            ffi::GRBgetbatchintattr(
+                batch.ptr,
+                b"BatchStatus\0".as_ptr() as ffi::c_str,
+                &mut status,
+            )
+        })?;
+        BatchStatus::try_from(status).map_err(Error::NotYetSupported)
+    }
+
+    /// Retrieve the solution of a completed batch, in Gurobi's JSON solution format.
+    ///
+    /// # Errors
+    /// Returns [`Error::FromAPI`] if the batch cannot be found or has not completed.
+    pub fn batch_retrieve(&self, id: &BatchId) -> Result<String> {
+        let batch = Batch::new(self, id)?;
+        let mut json = std::ptr::null();
+        self.check_apicall(unsafe { ffi::GRBgetbatchjsonsolution(batch.ptr, &mut json) })?;
+        Ok(unsafe { copy_c_str(json) })
+    }
+}
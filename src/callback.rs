@@ -100,6 +100,7 @@
 //! ```
 
 use std::borrow::Borrow;
+use std::cell::{Cell, RefCell};
 use std::convert::TryInto;
 use std::iter::{IntoIterator, Iterator};
 use std::os::raw;
@@ -109,7 +110,7 @@ use crate::constants::{callback::*, ERROR_CALLBACK, GRB_UNDEFINED};
 use crate::constr::IneqExpr;
 use crate::ffi;
 use crate::util::{self, AsPtr};
-use crate::{Error, Model, Result, Status, Var, INFINITY}; // used for setting a partial solution in a callback
+use crate::{Error, Model, ModelSense, Result, Status, Var, INFINITY}; // used for setting a partial solution in a callback
 
 /// The return type for callbacks, an alias of [`anyhow::Result`].
 ///
@@ -181,6 +182,13 @@ pub trait Callback {
     /// The main callback method.  The pattern-matching the [`Where`] will give a
     /// context object (see module-level docs) which can be used to interact with Gurobi.
     fn callback(&mut self, w: Where) -> CbResult;
+
+    /// Whether the solutions passed to [`Where::MIPSol`] callbacks should be recorded, which is required by
+    /// [`MIPNodeCtx::get_incumbent`].  Recording copies the full solution vector whenever an improving solution is
+    /// found, so it is disabled by default.  Closures can be wrapped in [`TrackIncumbent`] to enable it.
+    fn track_incumbent(&self) -> bool {
+        false
+    }
 }
 
 impl<F: FnMut(Where) -> CbResult> Callback for F {
@@ -189,6 +197,93 @@ impl<F: FnMut(Where) -> CbResult> Callback for F {
     }
 }
 
+/// A wrapper which enables incumbent recording (see [`Callback::track_incumbent`]) for the wrapped callback.
+///
+/// # Examples
+/// ```
+/// use grb::prelude::*;
+/// use grb::callback::TrackIncumbent;
+///
+/// let mut m = Model::new("model")?;
+/// let x = add_intvar!(m, obj: 1, bounds: 0..10)?;
+/// m.add_constr("c0", c!(2*x >= 3))?;
+///
+/// m.optimize_with_callback(&mut TrackIncumbent(|w: Where| {
+///     if let Where::MIPNode(ctx) = w {
+///         let _incumbent: Option<Vec<f64>> = ctx.get_incumbent([x])?;
+///     }
+///     Ok(())
+/// }))?;
+/// # Ok::<(), grb::Error>(())
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct TrackIncumbent<C>(pub C);
+
+impl<C: Callback> Callback for TrackIncumbent<C> {
+    fn callback(&mut self, w: Where) -> CbResult {
+        self.0.callback(w)
+    }
+
+    fn track_incumbent(&self) -> bool {
+        true
+    }
+}
+
+/// A ready-made callback which terminates a MIP solve once the relative gap between the best objective
+/// and the best objective bound drops below a given threshold.
+///
+/// The relative gap is computed as `|obj_best - obj_bnd| / |obj_best|` whenever Gurobi calls the callback from
+/// the [`Where::MIP`] or [`Where::MIPSol`] contexts.  When the optimisation is stopped by this callback, the
+/// model status will be [`Status::Interrupted`].  Note that the `MIPGap` parameter achieves a similar effect,
+/// but this callback can be combined with other callbacks or changed during the solve.
+///
+/// # Examples
+/// ```
+/// use grb::prelude::*;
+/// use grb::callback::GapTerminator;
+///
+/// let mut m = Model::new("model")?;
+/// let x = add_ctsvar!(m, obj: 2)?;
+/// let y = add_intvar!(m, bounds: 0..100)?;
+/// m.add_constr("c0", c!(x <= y - 0.5 ))?;
+///
+/// m.optimize_with_callback(&mut GapTerminator::new(0.01))?;
+/// # Ok::<(), grb::Error>(())
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct GapTerminator {
+    /// The relative gap threshold below which the optimisation is terminated.
+    pub rel_gap: f64,
+}
+
+impl GapTerminator {
+    /// Create a new `GapTerminator` with the given relative gap threshold.
+    pub fn new(rel_gap: f64) -> Self {
+        GapTerminator { rel_gap }
+    }
+
+    fn gap_closed(&self, obj_best: f64, obj_bnd: f64) -> bool {
+        // no incumbent found yet
+        if obj_best.abs() >= INFINITY {
+            return false;
+        }
+        (obj_best - obj_bnd).abs() <= self.rel_gap * obj_best.abs()
+    }
+}
+
+impl Callback for GapTerminator {
+    fn callback(&mut self, w: Where) -> CbResult {
+        match w {
+            Where::MIP(ctx) if self.gap_closed(ctx.obj_best()?, ctx.obj_bnd()?) => ctx.terminate(),
+            Where::MIPSol(ctx) if self.gap_closed(ctx.obj_best()?, ctx.obj_bnd()?) => {
+                ctx.terminate()
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+}
+
 /// The C function given to the Gurobi API with `GRBsetcallbackfunc`
 pub(crate) extern "C" fn callback_wrapper(
     _model: *mut ffi::GRBmodel,
@@ -198,10 +293,14 @@ pub(crate) extern "C" fn callback_wrapper(
 ) -> ffi::c_int {
     use std::panic::{catch_unwind, AssertUnwindSafe};
     let u = unsafe { &mut *usrdata.cast::<UserCallbackData>() };
-    let (cb_obj, model, nvars) = (&mut u.cb_obj, u.model, u.nvars);
-    let where_ = Where::new(CbCtx::new(cbdata, where_, model, nvars));
+    let (cb_obj, model, nvars, incumbent) = (&mut u.cb_obj, u.model, u.nvars, u.incumbent.as_ref());
+    let where_raw = where_;
+    if let Some(incumbent) = incumbent {
+        incumbent.lazy_added.set(false);
+    }
+    let where_ = Where::new(CbCtx::new(cbdata, where_, model, nvars, incumbent));
 
-    let callback_result = catch_unwind(AssertUnwindSafe(|| {
+    let callback_result = catch_unwind(AssertUnwindSafe(|| -> CbResult {
         let w = match where_ {
             Ok(w) => w,
             #[allow(unused_variables)]
@@ -211,7 +310,11 @@ pub(crate) extern "C" fn callback_wrapper(
             }
             Err(_) => unreachable!(),
         };
-        cb_obj.callback(w)
+        cb_obj.callback(w)?;
+        if where_raw == MIPSOL && incumbent.is_some() {
+            CbCtx::new(cbdata, where_raw, model, nvars, incumbent).record_incumbent()?;
+        }
+        Ok(())
     }));
 
     match callback_result {
@@ -232,6 +335,27 @@ pub(crate) struct UserCallbackData<'a> {
     pub(crate) model: &'a Model,
     pub(crate) nvars: usize,
     pub(crate) cb_obj: &'a mut dyn Callback,
+    pub(crate) incumbent: Option<IncumbentTracker>,
+}
+
+/// Gurobi doesn't provide the incumbent solution in a `MIPNODE` callback, so the best solution seen in a `MIPSOL`
+/// callback is recorded here for [`MIPNodeCtx::get_incumbent`], if the callback asks for it with
+/// [`Callback::track_incumbent`].
+pub(crate) struct IncumbentTracker {
+    minimize: bool,
+    best: RefCell<Option<(f64, Vec<f64>)>>,
+    /// Whether a lazy constraint was added in the current callback, in which case the `MIPSOL` solution is rejected.
+    lazy_added: Cell<bool>,
+}
+
+impl IncumbentTracker {
+    pub(crate) fn new(sense: ModelSense) -> Self {
+        IncumbentTracker {
+            minimize: sense == ModelSense::Minimize,
+            best: RefCell::new(None),
+            lazy_added: Cell::new(false),
+        }
+    }
 }
 
 macro_rules! impl_getter {
@@ -307,6 +431,12 @@ macro_rules! impl_common {
         pub fn proceed(&mut self) {
             self.0.proceed()
         }
+
+        /// Retrieve the handles of all variables in the model, in the order given by [`Model::get_vars`].
+        /// This saves capturing the handles in the callback before optimising.
+        pub fn model_vars(&self) -> Result<Vec<Var>> {
+            self.0.model_vars()
+        }
     };
 }
 
@@ -315,6 +445,10 @@ macro_rules! impl_add_lazy {
         /// Add a new lazy constraint to the model
         ///
         /// *Important*: Requires that the `LazyConstraints` parameter is set to 1
+        ///
+        /// # Errors
+        /// - [`Error::NotYetSupported`] if the constraint is quadratic, since Gurobi only supports linear lazy constraints.
+        /// - [`Error::FromAPI`] if a Gurobi error occurs while adding the constraint.
         pub fn add_lazy(&self, constr: IneqExpr) -> Result<()> {
             self.0.add_lazy(constr)
         }
@@ -418,6 +552,18 @@ impl<'a> MIPNodeCtx<'a> {
         self.0.add_cut(constr)
     }
 
+    /// Add multiple (linear) cutting planes to the MIP model.
+    ///
+    /// All cuts are checked for linearity before any are submitted, so if an error is returned
+    /// due to a nonlinear cut, no cuts have been added.
+    ///
+    /// # Errors
+    /// - [`Error::AlgebraicError`] if any of the cuts are nonlinear.
+    /// - [`Error::FromAPI`] if a Gurobi error occurs while adding the cuts.
+    pub fn add_cuts(&self, constrs: impl IntoIterator<Item = IneqExpr>) -> Result<()> {
+        self.0.add_cuts(constrs)
+    }
+
     /// Optimization status of current MIP node.
     pub fn status(&self) -> Result<Status> {
         self.0
@@ -435,6 +581,27 @@ impl<'a> MIPNodeCtx<'a> {
         self.0.get_node_rel(vars)
     }
 
+    /// Get the values of the given variables in the current incumbent (best known) solution, or `None` if no
+    /// feasible solution has been found yet.
+    ///
+    /// Gurobi does not provide the incumbent during a `MIPNODE` callback, so it is recorded from the solutions
+    /// passed to `MIPSOL` callbacks.  This must be enabled with [`Callback::track_incumbent`], for example by
+    /// wrapping the callback in [`TrackIncumbent`].  Solutions rejected by adding a lazy constraint are not recorded.
+    ///
+    /// The recorded solution is taken to be the incumbent if its objective value matches the best objective
+    /// reported by Gurobi to within a relative tolerance of `1e-9`.
+    ///
+    /// # Errors
+    /// - [`Error::DataNotAvailable`] if incumbent recording is disabled, or if the incumbent was not seen in a
+    ///   `MIPSOL` callback.
+    pub fn get_incumbent<I, V>(&self, vars: I) -> Result<Option<Vec<f64>>>
+    where
+        V: Borrow<Var>,
+        I: IntoIterator<Item = V>,
+    {
+        self.0.get_incumbent(vars)
+    }
+
     /// Current algorithmic phase in the MIP solution
     pub fn phase(&self) -> Result<MipPhase> {
         MipPhase::from_raw(self.0.get_int(MIPNODE, MIPNODE_PHASE)?)
@@ -570,6 +737,7 @@ struct CbCtx<'a> {
     cbdata: *mut ffi::c_void,
     model: &'a Model,
     nvars: usize,
+    incumbent: Option<&'a IncumbentTracker>,
 }
 
 impl<'a> CbCtx<'a> {
@@ -578,12 +746,14 @@ impl<'a> CbCtx<'a> {
         where_raw: i32,
         model: &'a Model,
         nvars: usize,
+        incumbent: Option<&'a IncumbentTracker>,
     ) -> Self {
         CbCtx {
             where_raw,
             cbdata,
             model,
             nvars,
+            incumbent,
         }
     }
 
@@ -608,6 +778,52 @@ impl<'a> CbCtx<'a> {
             .collect()
     }
 
+    /// Record the `MIPSOL` solution if it improves on the best solution seen so far and wasn't rejected.
+    fn record_incumbent(&self) -> Result<()> {
+        let incumbent = match self.incumbent {
+            Some(incumbent) if !incumbent.lazy_added.get() => incumbent,
+            _ => return Ok(()),
+        };
+        let obj = self.get_double(MIPSOL, MIPSOL_OBJ)?;
+        let mut best = incumbent.best.borrow_mut();
+        let improved = match &*best {
+            None => true,
+            Some((best_obj, _)) if incumbent.minimize => obj < *best_obj,
+            Some((best_obj, _)) => obj > *best_obj,
+        };
+        if improved {
+            *best = Some((obj, self.get_double_array_vars(MIPSOL, MIPSOL_SOL)?));
+        }
+        Ok(())
+    }
+
+    /// Retrieve values from the incumbent solution recorded by [`CbCtx::record_incumbent`].
+    pub fn get_incumbent<I, V>(&self, vars: I) -> Result<Option<Vec<f64>>>
+    where
+        V: Borrow<Var>,
+        I: IntoIterator<Item = V>,
+    {
+        let incumbent = self.incumbent.ok_or_else(|| {
+            Error::DataNotAvailable(
+                "incumbent recording is disabled, see Callback::track_incumbent".to_string(),
+            )
+        })?;
+        if self.get_int(MIPNODE, MIPNODE_SOLCNT)? == 0 {
+            return Ok(None);
+        }
+        let obj_best = self.get_double(MIPNODE, MIPNODE_OBJBST)?;
+        match &*incumbent.best.borrow() {
+            Some((obj, vals)) if (obj - obj_best).abs() <= 1e-9 * obj_best.abs().max(1.0) => vars
+                .into_iter()
+                .map(|v| Ok(vals[self.model.get_index(v.borrow())? as usize]))
+                .collect::<Result<_>>()
+                .map(Some),
+            _ => Err(Error::DataNotAvailable(
+                "incumbent solution was not seen in a MIPSOL callback".to_string(),
+            )),
+        }
+    }
+
     /// Retrieve values from the current solution vector.
     pub fn get_mip_solution<I, V>(&self, vars: I) -> Result<Vec<f64>>
     where
@@ -672,9 +888,44 @@ impl<'a> CbCtx<'a> {
         })
     }
 
+    /// Add multiple cutting planes to the MIP model, normalising all of them before submitting any.
+    pub fn add_cuts(&self, constrs: impl IntoIterator<Item = IneqExpr>) -> Result<()> {
+        let cuts = constrs
+            .into_iter()
+            .map(|c| {
+                let (lhs, sense, rhs) = c.into_normalised_linear()?;
+                let (inds, coeff) = self.model.get_coeffs_indices_build(&lhs)?;
+                Ok((inds, coeff, sense, rhs))
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        for (inds, coeff, sense, rhs) in cuts {
+            self.check_apicall(unsafe {
+                ffi::GRBcbcut(
+                    self.cbdata,
+                    coeff.len() as ffi::c_int,
+                    inds.as_ptr(),
+                    coeff.as_ptr(),
+                    sense as ffi::c_char,
+                    rhs,
+                )
+            })?;
+        }
+        Ok(())
+    }
+
     /// Add a new lazy constraint to the MIP model.
     pub fn add_lazy(&self, constr: IneqExpr) -> Result<()> {
-        let (lhs, sense, rhs) = constr.into_normalised_linear()?;
+        let (lhs, sense, rhs) = constr.into_normalised_quad();
+        let (qcoeffs, lhs) = lhs.into_parts();
+        if qcoeffs.values().any(|&a| a != 0.0) {
+            return Err(Error::NotYetSupported(
+                "quadratic lazy constraints: Gurobi only accepts linear lazy constraints from a callback. \
+                 Add a linearisation (for example, an outer-approximation cut) of the constraint instead, \
+                 or add the quadratic constraint to the model before optimising"
+                    .to_string(),
+            ));
+        }
         let (inds, coeff) = self.model.get_coeffs_indices_build(&lhs)?;
         self.check_apicall(unsafe {
             ffi::GRBcblazy(
@@ -685,13 +936,23 @@ impl<'a> CbCtx<'a> {
                 sense as ffi::c_char,
                 rhs,
             )
-        })
+        })?;
+        if let Some(incumbent) = self.incumbent {
+            incumbent.lazy_added.set(true);
+        }
+        Ok(())
     }
 
     pub fn terminate(&self) {
         self.model.terminate()
     }
 
+    /// Retrieve the handles of all variables in the model.
+    pub fn model_vars(&self) -> Result<Vec<Var>> {
+        // the model cannot be modified during a solve, and optimize() updates the model first
+        Ok(self.model.get_vars()?.to_vec())
+    }
+
     fn get_int(&self, where_: i32, what: i32) -> Result<i32> {
         let mut buf = 0i32;
         self.check_apicall(unsafe {
@@ -6,6 +6,8 @@ pub const GRB_MAX_STRLEN: usize = 512;
 pub const GRB_UNDEFINED: f64 = 1e101;
 /// A large constant used by Gurobi to represent numeric infinity.
 pub const GRB_INFINITY: f64 = 1e100;
+/// Negative numeric infinity, used as the lower bound of a free variable.
+pub const GRB_NEG_INFINITY: f64 = -GRB_INFINITY;
 
 pub const ERROR_CALLBACK: c_int = 10011;
 
@@ -159,7 +161,7 @@ impl TryFrom<c_char> for ConstrSense {
 }
 
 /// Sense of objective function, aka direction of optimisation.
-#[derive(Debug, Copy, Clone)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
 #[repr(i32)]
 pub enum ModelSense {
     /// Minimise the objective function
@@ -168,6 +170,35 @@ pub enum ModelSense {
     Maximize = -1,
 }
 
+impl ModelSense {
+    /// Returns [`ModelSense::Minimize`] if `minimize` is `true` and [`ModelSense::Maximize`] otherwise.
+    ///
+    /// # Examples
+    /// ```
+    /// # use grb::prelude::*;
+    /// assert_eq!(ModelSense::minimize_if(true), Minimize);
+    /// assert_eq!(ModelSense::minimize_if(false), Maximize);
+    /// ```
+    pub fn minimize_if(minimize: bool) -> ModelSense {
+        if minimize {
+            ModelSense::Minimize
+        } else {
+            ModelSense::Maximize
+        }
+    }
+}
+
+/// Converts the sign of the objective, `1` for minimisation and `-1` for maximisation, as used
+/// by the `ModelSense` attribute.
+///
+/// # Examples
+/// ```
+/// # use grb::prelude::*;
+/// use std::convert::TryFrom;
+/// assert_eq!(ModelSense::try_from(1), Ok(Minimize));
+/// assert_eq!(ModelSense::try_from(-1), Ok(Maximize));
+/// assert!(ModelSense::try_from(0).is_err());
+/// ```
 impl TryFrom<i32> for ModelSense {
     type Error = String;
     fn try_from(val: i32) -> std::result::Result<ModelSense, String> {
@@ -189,6 +220,17 @@ pub enum SOSType {
     Ty2 = 2,
 }
 
+impl TryFrom<i32> for SOSType {
+    type Error = String;
+    fn try_from(val: i32) -> std::result::Result<SOSType, String> {
+        match val {
+            1 => Ok(SOSType::Ty1),
+            2 => Ok(SOSType::Ty2),
+            _ => Err(format!("Invalid SOS type {val}, should be 1 or 2")),
+        }
+    }
+}
+
 /// Status of a model
 #[derive(Debug, Copy, Clone, PartialEq)]
 #[repr(i32)]
@@ -247,6 +289,21 @@ impl TryFrom<i32> for Status {
     }
 }
 
+/// Whether a model object must be included in, or excluded from, the IIS computed by
+/// [`Model::compute_iis_with_forces`](crate::Model::compute_iis_with_forces).
+///
+/// These are the values of the `IISConstrForce` attribute and its friends.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+#[repr(i32)]
+pub enum IisForce {
+    /// Let the IIS algorithm decide (the default)
+    Auto = -1,
+    /// Exclude the object from the IIS
+    Exclude = 0,
+    /// Always include the object in the IIS
+    Include = 1,
+}
+
 /// Type of cost function at feasibility relaxation
 #[derive(Debug, Copy, Clone)]
 #[repr(i32)]
@@ -264,10 +321,10 @@ pub enum RelaxType {
     Cardinality = 2,
 }
 
-/// Type of general constraint
+/// Type of general constraint, as given by the `GenConstrType` attribute.  See [`Model::get_genconstr_type`](crate::Model::get_genconstr_type).
 #[non_exhaustive]
 #[repr(i32)]
-#[derive(Copy, Clone, Eq, PartialEq, Hash)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
 pub enum GenConstrType {
     /// The constraint $r = \max\{x_1,\ldots,x_k,c\}$ states that
     /// the resultant variable $r$ should be equal to the maximum of
@@ -276,7 +333,7 @@ pub enum GenConstrType {
     /// For example, a solution $(r=3, x_1=2, x_2=3, x_3=0)$ would be feasible for
     /// the constraint $r = \max\{x_1,x_2,x_3,1.7\}$
     /// because $3$ is indeed the maximum of $2$, $3$, $0$, and $1.7$.
-    Max,
+    Max = 0,
     /// The constraint $r = \min\{x_1,\ldots,x_k,c\}$ states that
     /// the resultant variable $r$ should be equal to the minimum of
     /// the operand variables $x_1,\ldots,x_k$ and the constant $c$.
@@ -334,8 +391,6 @@ pub enum GenConstrType {
     NaturalLog,
     /// $y = \log_a(x)$, where $a > 0$ is the base for the logarithmic function
     Log,
-    /// $y = \frac{1}{1 + exp(-x)}$ or $y = \frac{1}{1 + e^{-x}}$
-    Logistic,
     /// $y = x^a$, where $x \geq 0$ for any $a$ and $x > 0$ for $a < 0$
     Pow,
     /// $y = \sin(x)$
@@ -344,14 +399,46 @@ pub enum GenConstrType {
     Cos,
     /// $y = \tan(x)$
     Tan,
+    /// $y = \frac{1}{1 + exp(-x)}$ or $y = \frac{1}{1 + e^{-x}}$
+    Logistic,
+    /// $y = f(x)$, where $f$ is a nonlinear expression, see `Model::add_genconstr_nl`.
+    /// Requires Gurobi 12.
+    Nl,
 }
 
 impl TryFrom<i32> for GenConstrType {
     type Error = String;
     fn try_from(val: i32) -> std::result::Result<Self, Self::Error> {
         match val {
-            1..=18 => Ok(unsafe { std::mem::transmute::<i32, GenConstrType>(val) }),
-            _ => Err("Invalid GenConstrType value, should be in [1,18]".to_string()),
+            0..=18 => Ok(unsafe { std::mem::transmute::<i32, GenConstrType>(val) }),
+            _ => Err("Invalid GenConstrType value, should be in [0,18]".to_string()),
+        }
+    }
+}
+
+/// File format of a model, used by [`Model::write_to_bytes`](crate::Model::write_to_bytes) and
+/// [`Model::from_bytes`](crate::Model::from_bytes).  See the
+/// [manual](https://docs.gurobi.com/projects/optimizer/en/current/reference/fileformats/modelformats.html) for details.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum ModelFormat {
+    /// MPS format (`.mps`)
+    Mps,
+    /// MPS format with generic variable and constraint names (`.rew`)
+    Rew,
+    /// LP format (`.lp`)
+    Lp,
+    /// LP format with generic variable and constraint names (`.rlp`)
+    Rlp,
+}
+
+impl ModelFormat {
+    /// The file name suffix Gurobi uses to select the reader or writer for this format.
+    pub(crate) fn suffix(self) -> &'static str {
+        match self {
+            ModelFormat::Mps => ".mps",
+            ModelFormat::Rew => ".rew",
+            ModelFormat::Lp => ".lp",
+            ModelFormat::Rlp => ".rlp",
         }
     }
 }
@@ -27,6 +27,9 @@ impl IneqExpr {
         Ok((lhs, sense, rhs))
     }
 
+    /// Move all variable terms (linear and quadratic) on the right-hand side to the left-hand side and
+    /// all constants to the right-hand side.  The sense is left unchanged, so `c!(4 >= x*x)` becomes
+    /// `-x*x >= -4`, which is equivalent to `x*x <= 4`.
     pub(crate) fn into_normalised_quad(self) -> (QuadExpr, ConstrSense, f64) {
         let IneqExpr { lhs, rhs, sense } = self;
         let mut lhs = (lhs - rhs).into_quadexpr();
@@ -98,4 +101,176 @@ impl fmt::Debug for Attached<'_, RangeExpr> {
     }
 }
 
+/// An indicator constraint expression: if `binvar` takes the value `binval`, then `constr` must hold.
+/// Creating this object does not automatically add the constraint to a model.  Instead, it should be passed to
+/// [`Model::add_indicator`](crate::Model::add_indicator).
+///
+/// Usually created with an invocation of `c!(BINVAR == 1 >> (LHS CMP RHS))`.
+/// Note that `constr` must be linear.
+#[derive(Debug, Clone)]
+pub struct IndicatorExpr {
+    /// The binary indicator variable
+    pub binvar: Var,
+    /// The value of the indicator variable which activates the constraint
+    pub binval: bool,
+    /// The constraint enforced when the indicator variable is `binval`
+    pub constr: IneqExpr,
+}
+
+impl AttachModel for IndicatorExpr {}
+
+impl fmt::Debug for Attached<'_, IndicatorExpr> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{:?} = {} → {:?}",
+            self.inner.binvar.attach(self.model),
+            self.inner.binval as u8,
+            self.inner.constr.attach(self.model)
+        )
+    }
+}
+
 // TODO: support for general PWL constraints
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    extern crate self as grb;
+
+    fn qcoeff(e: &QuadExpr, x: Var, y: Var) -> f64 {
+        e.iter_qterms()
+            .find(|((a, b), _)| (*a, *b) == (x, y) || (*a, *b) == (y, x))
+            .map_or(0.0, |(_, &c)| c)
+    }
+
+    fn vars() -> (Var, Var) {
+        (Var { id: 0, model_id: 0 }, Var { id: 1, model_id: 0 })
+    }
+
+    fn coeff(e: &LinExpr, x: Var) -> f64 {
+        e.iter_terms()
+            .find(|(v, _)| **v == x)
+            .map_or(0.0, |(_, &c)| c)
+    }
+
+    #[test]
+    fn linear_constants_both_sides() -> Result<()> {
+        let (x, y) = vars();
+        let (lhs, sense, rhs) = c!(x + 1 == y + 2).into_normalised_linear()?;
+        assert!(matches!(sense, ConstrSense::Equal));
+        assert_eq!(rhs, 1.0);
+        assert_eq!(lhs.num_terms(), 2);
+        assert_eq!(coeff(&lhs, x), 1.0);
+        assert_eq!(coeff(&lhs, y), -1.0);
+        assert_eq!(lhs.get_offset(), 0.0);
+
+        let (lhs, sense, rhs) = c!(2 * x + 3 <= 4 * y - 1).into_normalised_linear()?;
+        assert!(matches!(sense, ConstrSense::Less));
+        assert_eq!(rhs, -4.0);
+        assert_eq!(coeff(&lhs, x), 2.0);
+        assert_eq!(coeff(&lhs, y), -4.0);
+        assert_eq!(lhs.get_offset(), 0.0);
+
+        // constant-only sides
+        let (lhs, sense, rhs) = c!(5 >= x + 2).into_normalised_linear()?;
+        assert!(matches!(sense, ConstrSense::Greater));
+        assert_eq!(rhs, -3.0);
+        assert_eq!(lhs.num_terms(), 1);
+        assert_eq!(coeff(&lhs, x), -1.0);
+
+        let (lhs, _, rhs) = c!(x - y - 7 <= 0).into_normalised_linear()?;
+        assert_eq!(rhs, 7.0);
+        assert_eq!(coeff(&lhs, x), 1.0);
+        assert_eq!(coeff(&lhs, y), -1.0);
+        assert_eq!(lhs.get_offset(), 0.0);
+
+        // constants on both sides of a single-variable constraint, with a variable appearing on both sides
+        let (lhs, _, rhs) = c!(3 * x - 2 == x + 2 - 0.5).into_normalised_linear()?;
+        assert_eq!(rhs, 3.5);
+        assert_eq!(lhs.num_terms(), 1);
+        assert_eq!(coeff(&lhs, x), 2.0);
+        Ok(())
+    }
+
+    #[test]
+    fn indicator() {
+        let (b, x) = vars();
+        let ind = c!(b == 1 >> (x + 2 <= 4));
+        assert_eq!(ind.binvar, b);
+        assert!(ind.binval);
+        let (lhs, sense, rhs) = ind.constr.into_normalised_linear().unwrap();
+        assert!(matches!(sense, ConstrSense::Less));
+        assert_eq!(rhs, 2.0);
+        assert_eq!(coeff(&lhs, x), 1.0);
+
+        let ind = c!((b == 0) >> (x >= 1));
+        assert!(!ind.binval);
+        assert!(matches!(ind.constr.sense, ConstrSense::Greater));
+
+        // a bit-shift on the right-hand side is not an implication
+        let k = 2;
+        let (_, _, rhs) = c!(x == 8 >> k).into_normalised_linear().unwrap();
+        assert_eq!(rhs, 2.0);
+    }
+
+    #[test]
+    fn inclusive_range() -> Result<()> {
+        let (x, y) = vars();
+        let (e1, lb1, ub1) = c!(x + y in 0..=10).into_normalised()?;
+        let (e2, lb2, ub2) = c!(x + y in 0..10).into_normalised()?;
+        assert_eq!((lb1, ub1), (0.0, 10.0));
+        assert_eq!((lb1, ub1), (lb2, ub2));
+        assert_eq!(e1.num_terms(), 2);
+        assert_eq!(coeff(&e1, x), coeff(&e2, x));
+        assert_eq!(coeff(&e1, y), coeff(&e2, y));
+
+        let (_, lb, ub) = c!(x in ..=1).into_normalised()?;
+        assert_eq!((lb, ub), (-INFINITY, 1.0));
+        Ok(())
+    }
+
+    #[test]
+    fn quad_lhs() {
+        let (x, _) = vars();
+        let (lhs, sense, rhs) = c!(x * x <= 4).into_normalised_quad();
+        assert!(matches!(sense, ConstrSense::Less));
+        assert_eq!(rhs, 4.0);
+        assert_eq!(lhs.num_qterms(), 1);
+        assert_eq!(qcoeff(&lhs, x, x), 1.0);
+        assert_eq!(lhs.get_offset(), 0.0);
+    }
+
+    #[test]
+    fn quad_rhs() {
+        let (x, _) = vars();
+        let (lhs, sense, rhs) = c!(4 >= x * x).into_normalised_quad();
+        // equivalent to x*x <= 4
+        assert!(matches!(sense, ConstrSense::Greater));
+        assert_eq!(rhs, -4.0);
+        assert_eq!(lhs.num_qterms(), 1);
+        assert_eq!(qcoeff(&lhs, x, x), -1.0);
+        assert_eq!(lhs.get_offset(), 0.0);
+    }
+
+    #[test]
+    fn quad_both_sides() {
+        let (x, y) = vars();
+        let (lhs, sense, rhs) = c!(x * x <= y * y).into_normalised_quad();
+        assert!(matches!(sense, ConstrSense::Less));
+        assert_eq!(rhs, 0.0);
+        assert_eq!(lhs.num_qterms(), 2);
+        assert_eq!(qcoeff(&lhs, x, x), 1.0);
+        assert_eq!(qcoeff(&lhs, y, y), -1.0);
+
+        let (lhs, sense, rhs) = c!(x * y + x + 1 == 3 * (y * x) - y + 2).into_normalised_quad();
+        assert!(matches!(sense, ConstrSense::Equal));
+        assert_eq!(rhs, 1.0);
+        assert_eq!(lhs.num_qterms(), 1);
+        assert_eq!(qcoeff(&lhs, x, y), -2.0);
+        assert_eq!(lhs.num_terms(), 2);
+        let lin: Vec<_> = lhs.iter_terms().map(|(&v, &c)| (v.id, c)).collect();
+        assert!(lin.contains(&(0, 1.0)));
+        assert!(lin.contains(&(1, 1.0)));
+    }
+}
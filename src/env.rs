@@ -3,9 +3,10 @@ use std::ptr::null_mut;
 use std::rc::Rc;
 
 use crate::ffi;
-use crate::parameter::{ParamGet, ParamSet};
+use crate::parameter::{DoubleParam, IntParam, ParamGet, ParamInfo, ParamSet};
 use crate::util;
 use crate::{Error, Result};
+use cstr_enum::AsCStr;
 use util::AsPtr;
 
 /// Represents a User-Allocated Gurobi Env
@@ -58,6 +59,10 @@ impl AsPtr for Env {
 /// Setting [`param::OutputFlag`](crate::param::OutputFlag) to 0 on an `EmptyEnv` is useful for stopping the license info from
 /// being printed, since this happens on starting the environment.
 ///
+/// Connection parameters for a Compute Server or Cluster Manager, such as [`param::ComputeServer`](crate::param::ComputeServer)
+/// and [`param::ServerPassword`](crate::param::ServerPassword), must also be set before the environment is started.
+/// See also [`Env::from_client_params`].
+///
 /// # Examples
 /// ```
 /// use grb::*;
@@ -92,6 +97,111 @@ impl EmptyEnv {
     }
 }
 
+/// A builder for an [`Env`] which collects license credentials before the environment is started.
+///
+/// Credentials for the Gurobi Web License Service (WLS) must be set on an empty environment before it is
+/// started, which this builder takes care of.  For other parameters which must be set before starting,
+/// use [`Env::empty`].
+///
+/// # Examples
+/// ```no_run
+/// # use grb::prelude::*;
+/// use grb::EnvBuilder;
+/// let env = EnvBuilder::new()
+///     .log_file("gurobi.log")
+///     .wls("my-access-id", "my-secret", 123456)
+///     .start()?;
+/// let mut m = Model::with_env("model", &env)?;
+/// # Ok::<(), grb::Error>(())
+/// ```
+#[derive(Clone, Default)]
+pub struct EnvBuilder {
+    log_file: Option<String>,
+    wls: Option<WlsCredentials>,
+}
+
+#[derive(Clone)]
+struct WlsCredentials {
+    access_id: String,
+    secret: String,
+    license_id: i32,
+}
+
+impl std::fmt::Debug for EnvBuilder {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        // never print the WLS secret
+        let wls = self
+            .wls
+            .as_ref()
+            .map(|c| (&c.access_id, "<secret>", c.license_id));
+        f.debug_struct("EnvBuilder")
+            .field("log_file", &self.log_file)
+            .field("wls", &wls)
+            .finish()
+    }
+}
+
+impl EnvBuilder {
+    /// Create a new builder.  Without further configuration, this creates the same environment as `Env::new("")`.
+    pub fn new() -> EnvBuilder {
+        EnvBuilder::default()
+    }
+
+    /// Set the log file of the environment.
+    pub fn log_file(mut self, filename: impl Into<String>) -> EnvBuilder {
+        self.log_file = Some(filename.into());
+        self
+    }
+
+    /// Use a Web License Service (WLS) license, given its access ID, secret and license ID.  These set the
+    /// [`WLSAccessID`](crate::param::WLSAccessID), [`WLSSecret`](crate::param::WLSSecret) and
+    /// [`LicenseID`](crate::param::LicenseID) parameters respectively.
+    pub fn wls(
+        mut self,
+        access_id: impl Into<String>,
+        secret: impl Into<String>,
+        license_id: i32,
+    ) -> EnvBuilder {
+        self.wls = Some(WlsCredentials {
+            access_id: access_id.into(),
+            secret: secret.into(),
+            license_id,
+        });
+        self
+    }
+
+    /// Create and start the environment.
+    ///
+    /// # Errors
+    /// Returns [`Error::FromAPI`] if the environment cannot be started.  If WLS credentials were given, the
+    /// message states which license failed, for example because the credentials were rejected.
+    pub fn start(self) -> Result<Env> {
+        let mut env = Env::empty()?;
+        if let Some(log_file) = self.log_file {
+            env.set(crate::param::LogFile, log_file)?;
+        }
+        match self.wls {
+            None => env.start(),
+            Some(creds) => {
+                let license_id = creds.license_id;
+                env.set(crate::param::WLSAccessID, creds.access_id)?
+                    .set(crate::param::WLSSecret, creds.secret)?
+                    .set(crate::param::LicenseID, license_id)?;
+                env.start().map_err(|e| match e {
+                    Error::FromAPI(msg, code) => Error::FromAPI(
+                        format!(
+                            "failed to start environment with WLS license {}: {}",
+                            license_id, msg
+                        ),
+                        code,
+                    ),
+                    e => e,
+                })
+            }
+        }
+    }
+}
+
 impl Env {
     thread_local!(pub(crate) static DEFAULT_ENV : Env = Env::new("gurobi.log").unwrap());
 
@@ -136,7 +246,9 @@ impl Env {
 
     /// Create an environment with log file
     ///
-    /// Setting `logfilename` to an empty string will not create a logfile.
+    /// Setting `logfilename` to an empty string will not create a logfile.  However, Gurobi may still
+    /// pick up a `LogFile` setting from a `gurobi.env` file in the working directory.  Use [`Env::new_no_log`]
+    /// to guarantee no log file is written.
     pub fn new(logfilename: &str) -> Result<Env> {
         let mut env = null_mut();
         let logfilename = CString::new(logfilename)?;
@@ -147,6 +259,72 @@ impl Env {
         Ok(unsafe { Env::new_user_allocated(env) })
     }
 
+    /// Create an environment connected to a Gurobi Compute Server, the equivalent of the `GRBloadclientenv` routine
+    /// of older Gurobi versions.
+    ///
+    /// `server` is the name of the server, with an optional port (for example `"server1:61000"`).  Multiple servers
+    /// can be given as a comma-separated list.  `password` is the server's client password, and may be empty.
+    /// Further connection parameters, such as [`param::CSRouter`](crate::param::CSRouter) or
+    /// [`param::CSPriority`](crate::param::CSPriority), can be set by using [`Env::empty`] instead.
+    ///
+    /// # Errors
+    /// Returns [`Error::FromAPI`] if the server cannot be reached or rejects the connection.
+    pub fn from_client_params(logfilename: &str, server: &str, password: &str) -> Result<Env> {
+        let mut env = Env::empty()?;
+        env.set(crate::param::LogFile, logfilename.to_string())?
+            .set(crate::param::ComputeServer, server.to_string())?
+            .set(crate::param::ServerPassword, password.to_string())?;
+        env.start()
+    }
+
+    /// Create an environment which writes no log file and prints no output.
+    ///
+    /// Unlike `Env::new("")`, the [`param::LogFile`](crate::param::LogFile) parameter is explicitly cleared and
+    /// [`param::OutputFlag`](crate::param::OutputFlag) is set to 0 *before* the environment is started,
+    /// so settings from a `gurobi.env` file are overridden and the license banner is not printed.
+    ///
+    /// # Examples
+    /// ```
+    /// # use grb::prelude::*;
+    /// let env = Env::new_no_log()?;
+    /// assert_eq!(env.get(param::LogFile)?, "");
+    /// assert_eq!(env.get(param::OutputFlag)?, 0);
+    /// # Ok::<(), grb::Error>(())
+    /// ```
+    pub fn new_no_log() -> Result<Env> {
+        let mut env = Env::empty()?;
+        env.set(crate::param::LogFile, String::new())?
+            .set(crate::param::LogToConsole, 0)?
+            .set(crate::param::OutputFlag, 0)?;
+        env.start()
+    }
+
+    /// Create a new, independent environment with the same parameter settings as this one.
+    ///
+    /// A new `GRBenv` is created and all parameters are copied into it before it is started, so later
+    /// changes to either environment do not affect the other.  Only parameters are shared: the new
+    /// environment obtains its own license token, and any connection state (Compute Server, Instant Cloud)
+    /// is re-established from the copied parameters rather than shared.
+    ///
+    /// # Examples
+    /// ```
+    /// # use grb::prelude::*;
+    /// let mut env = Env::new("")?;
+    /// env.set(param::Threads, 2)?;
+    /// let mut fork = env.fork()?;
+    /// assert_eq!(fork.get(param::Threads)?, 2);
+    /// fork.set(param::Threads, 1)?;
+    /// assert_eq!(env.get(param::Threads)?, 2);
+    /// # Ok::<(), grb::Error>(())
+    /// ```
+    pub fn fork(&self) -> Result<Env> {
+        let env = Env::empty()?;
+        env.env.check_apicall(unsafe {
+            ffi::GRBcopyparams(env.env.as_mut_ptr(), self.as_mut_ptr())
+        })?;
+        env.start()
+    }
+
     /// Query the value of a parameter
     pub fn get<P: ParamGet<V>, V>(&self, param: P) -> Result<V> {
         param.get(self)
@@ -157,6 +335,68 @@ impl Env {
         param.set(self, value)
     }
 
+    /// Query the current value, legal range and default value of an integer parameter.
+    ///
+    /// # Examples
+    /// ```
+    /// # use grb::prelude::*;
+    /// let env = Env::new("")?;
+    /// let info = env.get_int_param_info(param::Threads)?;
+    /// assert_eq!(info.default, 0);
+    /// assert!(info.min <= info.value && info.value <= info.max);
+    /// # Ok::<(), grb::Error>(())
+    /// ```
+    pub fn get_int_param_info(&self, param: IntParam) -> Result<ParamInfo<i32>> {
+        let (mut value, mut min, mut max, mut default) = (0, 0, 0, 0);
+        self.check_apicall(unsafe {
+            ffi::GRBgetintparaminfo(
+                self.as_mut_ptr(),
+                param.as_cstr().as_ptr(),
+                &mut value,
+                &mut min,
+                &mut max,
+                &mut default,
+            )
+        })?;
+        Ok(ParamInfo {
+            value,
+            min,
+            max,
+            default,
+        })
+    }
+
+    /// Query the current value, legal range and default value of a double parameter.
+    ///
+    /// # Examples
+    /// ```
+    /// # use grb::prelude::*;
+    /// let env = Env::new("")?;
+    /// let info = env.get_double_param_info(param::MIPGap)?;
+    /// assert_eq!(info.min, 0.0);
+    /// assert_eq!(info.default, 1e-4);
+    /// # Ok::<(), grb::Error>(())
+    /// ```
+    pub fn get_double_param_info(&self, param: DoubleParam) -> Result<ParamInfo<f64>> {
+        let (mut value, mut min, mut max, mut default) = (0.0, 0.0, 0.0, 0.0);
+        self.check_apicall(unsafe {
+            ffi::GRBgetdblparaminfo(
+                self.as_mut_ptr(),
+                param.as_cstr().as_ptr(),
+                &mut value,
+                &mut min,
+                &mut max,
+                &mut default,
+            )
+        })?;
+        Ok(ParamInfo {
+            value,
+            min,
+            max,
+            default,
+        })
+    }
+
     /// Import a set of parameter values from a file
     pub fn read_params(&mut self, filename: &str) -> Result<()> {
         let filename = CString::new(filename)?;
@@ -209,6 +449,75 @@ mod tests {
         assert!(env.set(param::IISMethod, 9999).is_err());
     }
 
+    #[test]
+    fn fork() -> Result<()> {
+        let mut env = Env::new("")?;
+        env.set(param::OutputFlag, 0)?;
+        env.set(param::MIPGap, 0.25)?;
+
+        let mut fork = env.fork()?;
+        assert_ne!(&env.user_allocated, &fork.user_allocated);
+        assert_eq!(fork.get(param::MIPGap)?, 0.25);
+        assert_eq!(fork.get(param::OutputFlag)?, 0);
+
+        fork.set(param::MIPGap, 0.5)?;
+        assert_eq!(env.get(param::MIPGap)?, 0.25);
+        env.set(param::MIPGap, 0.1)?;
+        assert_eq!(fork.get(param::MIPGap)?, 0.5);
+        Ok(())
+    }
+
+    #[test]
+    fn param_info() -> Result<()> {
+        let mut env = Env::new("")?;
+        env.set(param::OutputFlag, 0)?;
+        env.set(param::IISMethod, 1)?;
+        let info = env.get_int_param_info(param::IISMethod)?;
+        assert_eq!(
+            info,
+            ParamInfo {
+                value: 1,
+                min: -1,
+                max: 3,
+                default: -1
+            }
+        );
+
+        env.set(param::TimeLimit, 10.0)?;
+        let info = env.get_double_param_info(param::TimeLimit)?;
+        assert_eq!(info.value, 10.0);
+        assert_eq!(info.min, 0.0);
+        assert_eq!(info.default, crate::INFINITY);
+        Ok(())
+    }
+
+    #[test]
+    fn client_params_unreachable_server() {
+        let err = Env::from_client_params("", "127.0.0.1:1", "").err();
+        assert!(matches!(err, Some(Error::FromAPI(_, _))));
+    }
+
+    #[test]
+    fn env_builder() -> Result<()> {
+        let env = EnvBuilder::new().start()?;
+        assert_eq!(env.get(param::LogFile)?, "");
+
+        let builder = EnvBuilder::new().wls("not-an-id", "not-a-secret", 1);
+        assert!(!format!("{:?}", builder).contains("not-a-secret"));
+        Ok(())
+    }
+
+    #[test]
+    #[ignore = "contacts the WLS license servers"]
+    fn env_builder_wls_start() -> Result<()> {
+        let builder = EnvBuilder::new().wls("not-an-id", "not-a-secret", 1);
+        match builder.start() {
+            Err(Error::FromAPI(msg, _)) => assert!(msg.contains("WLS license 1"), "{}", msg),
+            other => panic!("expected FromAPI error, got {:?}", other.map(|_| ())),
+        }
+        Ok(())
+    }
+
     #[test]
     fn default_env_created_once() -> Result<()> {
         let m1 = Model::new("m1")?;
@@ -1,5 +1,9 @@
 /// The error type for operations in Gurobi Rust API
-#[derive(Debug, Eq, PartialEq)]
+///
+/// Two [`Error::FromAPI`] errors compare equal if their error *codes* are equal; the message is
+/// excluded from equality since it is not stable across Gurobi versions.  Likewise, two [`Error::Io`] errors
+/// compare equal if their [`kinds`](std::io::Error::kind) are equal.  All other variants compare as usual.
+#[derive(Debug)]
 pub enum Error {
     /// An error returned from Gurobi C API.  Contains the message and the error code.
     FromAPI(String, i32),
@@ -18,8 +22,48 @@ pub enum Error {
     AlgebraicError(String),
     /// Gurobi feature not yet supported by this crate. Currently for internal use only.
     NotYetSupported(String),
+    /// The requested information is not available for the model in its current state, for example a MIP-only
+    /// attribute queried on a continuous model.  Unlike [`Error::FromAPI`], this is detected by the crate itself
+    /// before calling Gurobi.
+    DataNotAvailable(String),
+    /// An argument passed to a method of this crate is invalid, for example an index which is out of range.  Unlike
+    /// [`Error::FromAPI`], this is detected by the crate itself before calling Gurobi.
+    InvalidArgument(String),
+    /// An I/O error from a file accessed by this crate (rather than by Gurobi), such as the temporary files used by
+    /// [`Model::write_to_bytes`](crate::Model::write_to_bytes).
+    Io(std::io::Error),
 }
 
+impl Error {
+    /// Create an [`Error::FromAPI`] with the given error code and an empty message.  Mainly useful for comparing
+    /// against errors returned by this crate, for example `assert_eq!(err, Error::from_api_code(10005))`.
+    pub fn from_api_code(code: i32) -> Error {
+        Error::FromAPI(String::new(), code)
+    }
+}
+
+impl PartialEq for Error {
+    fn eq(&self, other: &Error) -> bool {
+        use Error::*;
+        match (self, other) {
+            (FromAPI(_, a), FromAPI(_, b)) => a == b,
+            (NulError(a), NulError(b)) => a == b,
+            (ModelObjectRemoved, ModelObjectRemoved)
+            | (ModelObjectPending, ModelObjectPending)
+            | (ModelObjectMismatch, ModelObjectMismatch)
+            | (ModelUpdateNeeded, ModelUpdateNeeded) => true,
+            (AlgebraicError(a), AlgebraicError(b)) => a == b,
+            (NotYetSupported(a), NotYetSupported(b)) => a == b,
+            (DataNotAvailable(a), DataNotAvailable(b)) => a == b,
+            (InvalidArgument(a), InvalidArgument(b)) => a == b,
+            (Io(a), Io(b)) => a.kind() == b.kind(),
+            _ => false,
+        }
+    }
+}
+
+impl Eq for Error {}
+
 impl From<std::ffi::NulError> for Error {
     fn from(err: std::ffi::NulError) -> Error {
         Error::NulError(err)
@@ -39,12 +83,53 @@ impl std::fmt::Display for Error {
             }
             Error::AlgebraicError(s) => &format!("Algebraic error: {s}"),
             Error::NotYetSupported(s) => &format!("Not yet supported: {s}"),
+            Error::DataNotAvailable(s) => &format!("Data not available: {s}"),
+            Error::InvalidArgument(s) => &format!("Invalid argument: {s}"),
+            Error::Io(err) => &format!("I/O error: {err}"),
         };
         f.write_str(msg)
     }
 }
 
-impl std::error::Error for Error {}
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::NulError(err) => Some(err),
+            Error::Io(err) => Some(err),
+            _ => None,
+        }
+    }
+}
 
 /// A specialized [`std::result::Result`] for library errors
 pub type Result<T> = std::result::Result<T, Error>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn from_api_eq_by_code() {
+        let a = Error::FromAPI("Unable to retrieve attribute 'X'".to_string(), 10005);
+        let b = Error::FromAPI("some other message".to_string(), 10005);
+        assert_eq!(a, b);
+        assert_eq!(a, Error::from_api_code(10005));
+        assert_ne!(a, Error::from_api_code(10003));
+        assert_ne!(
+            a,
+            Error::AlgebraicError("Unable to retrieve attribute 'X'".to_string())
+        );
+        assert_eq!(Error::ModelObjectPending, Error::ModelObjectPending);
+        assert_ne!(Error::ModelObjectPending, Error::ModelObjectRemoved);
+    }
+
+    #[test]
+    fn io_eq_by_kind() {
+        use std::io;
+        let a = Error::Io(io::Error::new(io::ErrorKind::NotFound, "a.lp"));
+        let b = Error::Io(io::Error::new(io::ErrorKind::NotFound, "b.lp"));
+        assert_eq!(a, b);
+        assert_ne!(a, Error::Io(io::ErrorKind::PermissionDenied.into()));
+        assert!(std::error::Error::source(&a).is_some());
+    }
+}
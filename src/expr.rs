@@ -2,6 +2,7 @@
 //! Algebraic expressions involving variables used to construct constraints and a helper trait for pretty-printing.
 
 use fnv::FnvHashMap;
+use std::collections::hash_map::Entry;
 use std::fmt;
 use std::fmt::Write;
 use std::iter::Sum;
@@ -155,6 +156,22 @@ impl LinExpr {
         self
     }
 
+    /// Like [`LinExpr::add_term`], but removes the variable from the expression if its coefficient cancels
+    /// out to (close to) zero.  Used when combining expressions with `+` and `-`.
+    fn merge_term(&mut self, coeff: f64, var: Var) {
+        match self.coeff.entry(var) {
+            Entry::Occupied(mut e) => {
+                *e.get_mut() += coeff;
+                if e.get().abs() < f64::EPSILON {
+                    e.remove();
+                }
+            }
+            Entry::Vacant(e) => {
+                e.insert(coeff);
+            }
+        }
+    }
+
     /// Get the constant offset
     pub fn get_offset(&self) -> f64 {
         self.offset
@@ -188,6 +205,15 @@ impl LinExpr {
         self.coeff.iter()
     }
 
+    /// Iterate over the terms in the expression in a deterministic order: variables are sorted in the order
+    /// they were added to the model, which matches their order in the model.  Unlike [`LinExpr::iter_terms`],
+    /// the order does not depend on how the expression was built.
+    pub fn iter_terms_sorted(&self) -> std::vec::IntoIter<(&Var, &f64)> {
+        let mut terms: Vec<_> = self.coeff.iter().collect();
+        terms.sort_unstable_by_key(|(v, _)| (v.model_id, v.id));
+        terms.into_iter()
+    }
+
     /// Multiply expression by a scalar
     pub fn mul_scalar(&mut self, val: f64) -> &mut Self {
         self.offset *= val;
@@ -199,6 +225,28 @@ impl LinExpr {
     pub fn sparsify(&mut self) {
         self.coeff.retain(|_, a| a.abs() > f64::EPSILON);
     }
+
+    /// Format the expression using the `VarName` attribute of each variable, for example `3 x + 2 y - z + 5`.
+    /// Terms are written in the order of [`LinExpr::iter_terms_sorted`], followed by the constant.
+    ///
+    /// # Errors
+    /// Returns an error if a variable's name cannot be queried, for example if the variable belongs to a different
+    /// model or the model needs to be updated first.
+    pub fn to_string_with_names(&self, model: &Model) -> Result<String> {
+        let mut s = String::new();
+        self.write_named_terms(&mut s, model)?;
+        push_named_constant(&mut s, self.offset);
+        Ok(s)
+    }
+
+    fn write_named_terms(&self, s: &mut String, model: &Model) -> Result<()> {
+        let (vars, coeffs): (Vec<_>, Vec<_>) = self.iter_terms_sorted().unzip();
+        let names = model.get_obj_attr_batch(attr::VarName, vars.into_iter().copied())?;
+        for (name, &a) in names.iter().zip(coeffs) {
+            push_named_term(s, a, name);
+        }
+        Ok(())
+    }
 }
 
 impl QuadExpr {
@@ -302,11 +350,42 @@ impl QuadExpr {
         self.qcoeffs.iter()
     }
 
+    /// Iterate over the quadratic terms in the expression in a deterministic order, sorted by the first variable
+    /// and then the second.  See [`LinExpr::iter_terms_sorted`].
+    pub fn iter_qterms_sorted(&self) -> std::vec::IntoIter<(&(Var, Var), &f64)> {
+        let mut terms: Vec<_> = self.qcoeffs.iter().collect();
+        terms.sort_unstable_by_key(|((x, y), _)| (x.model_id, x.id, y.model_id, y.id));
+        terms.into_iter()
+    }
+
     /// Remove variable terms whose coefficients are less than or equal to [`f64::EPSILON`].
     pub fn sparsify(&mut self) {
         self.linexpr.sparsify();
         self.qcoeffs.retain(|_, a| a.abs() > f64::EPSILON);
     }
+
+    /// Format the expression using the `VarName` attribute of each variable, for example `-y + x^2 + 2 x*y + 1`.
+    /// Linear terms are written first, followed by the quadratic terms (in the order of
+    /// [`QuadExpr::iter_qterms_sorted`]) and the constant.  See [`LinExpr::to_string_with_names`].
+    pub fn to_string_with_names(&self, model: &Model) -> Result<String> {
+        let mut s = String::new();
+        self.linexpr.write_named_terms(&mut s, model)?;
+        let (pairs, coeffs): (Vec<_>, Vec<_>) = self.iter_qterms_sorted().unzip();
+        let xnames = model.get_obj_attr_batch(attr::VarName, pairs.iter().map(|(x, _)| *x))?;
+        let ynames = model.get_obj_attr_batch(attr::VarName, pairs.iter().map(|(_, y)| *y))?;
+        for ((&(x, y), &a), (xname, yname)) in
+            pairs.iter().zip(coeffs).zip(xnames.iter().zip(&ynames))
+        {
+            let term = if x == y {
+                format!("{xname}^2")
+            } else {
+                format!("{xname}*{yname}")
+            };
+            push_named_term(&mut s, a, &term);
+        }
+        push_named_constant(&mut s, self.linexpr.offset);
+        Ok(s)
+    }
 }
 
 impl Add for Expr {
@@ -338,7 +417,7 @@ impl Add for Expr {
             (Term(a, x), Term(b, y)) => {
                 let mut e = LinExpr::new();
                 e.add_term(a, x);
-                e.add_term(b, y);
+                e.merge_term(b, y);
                 e.into()
             }
             (Term(a, x), QTerm(b, y1, y2)) => {
@@ -348,11 +427,11 @@ impl Add for Expr {
                 e.into()
             }
             (Term(a, x), Linear(mut e)) => {
-                e.add_term(a, x);
+                e.merge_term(a, x);
                 e.into()
             }
             (Term(a, x), Quad(mut e)) => {
-                e.add_term(a, x);
+                e.linexpr.merge_term(a, x);
                 e.into()
             }
             (QTerm(a, x1, x2), QTerm(b, y1, y2)) => {
@@ -377,7 +456,7 @@ impl Add for Expr {
                 let (coeffs, c) = e2.into_parts();
                 e1.add_constant(c);
                 for (x, a) in coeffs {
-                    e1.add_term(a, x);
+                    e1.merge_term(a, x);
                 }
                 e1.into()
             }
@@ -425,6 +504,36 @@ impl From<QuadExpr> for Expr {
     }
 }
 
+/// Build a linear expression from `(var, coeff)` terms.  Coefficients of duplicate variables are summed.
+impl From<Vec<(Var, f64)>> for LinExpr {
+    fn from(terms: Vec<(Var, f64)>) -> LinExpr {
+        LinExpr::from(terms.as_slice())
+    }
+}
+
+/// Build a linear expression from `(var, coeff)` terms.  Coefficients of duplicate variables are summed.
+impl From<&[(Var, f64)]> for LinExpr {
+    fn from(terms: &[(Var, f64)]) -> LinExpr {
+        let mut e = LinExpr::new();
+        for &(var, coeff) in terms {
+            e.add_term(coeff, var);
+        }
+        e
+    }
+}
+
+/// Build a quadratic expression from `((row_var, col_var), coeff)` terms.  Coefficients of duplicate terms are
+/// summed, treating `(x, y)` and `(y, x)` as the same term.
+impl From<Vec<((Var, Var), f64)>> for QuadExpr {
+    fn from(terms: Vec<((Var, Var), f64)>) -> QuadExpr {
+        let mut e = QuadExpr::new();
+        for ((x, y), coeff) in terms {
+            e.add_qterm(coeff, x, y);
+        }
+        e
+    }
+}
+
 impl<T: Copy + Into<Expr>> From<&T> for Expr {
     fn from(val: &T) -> Expr {
         (*val).into()
@@ -655,6 +764,21 @@ impl<A: Into<Expr>> Sum<A> for Expr {
 /// ```
 /// This may or may not be more ergonomic.
 ///
+/// The items may be any type convertible to [`Expr`], including quadratic expressions.  Coefficients of
+/// repeated linear and quadratic terms are combined, and [`Expr::into_quadexpr`] recovers a [`QuadExpr`]:
+/// ```
+/// # use grb::prelude::*;
+/// # let mut model = Model::new("")?;
+/// # let x = add_ctsvar!(model)?;
+/// # let y = add_ctsvar!(model)?;
+/// # let vars = [x, y];
+/// // sum_i x_i^2 + x_i
+/// let obj = vars.iter().map(|&v| v * v + v).grb_sum().into_quadexpr();
+/// assert_eq!(obj.num_qterms(), 2);
+/// model.set_objective(obj, Minimize)?;
+/// # Ok::<(), grb::Error>(())
+/// ```
+///
 /// TLDR: Use `.grb_sum()` instead of `sum()` when summing over an iterator of variables or variable expressions.
 pub trait GurobiSum {
     /// Additively combine an iterator (or container) of one or more expressions into a single expression.
@@ -701,7 +825,8 @@ pub struct Attached<'a, T> {
 ///
 /// The `AttachModel` trait provides an `.attach(&model)` method, with simply bundles a `&`[`Model`] with
 /// a reference to the object. The [`Debug`] trait is implemented for this bundled type ([`Attached`])
-/// and properly queries the model for variable names. Because querying the `VarName` of a variable can fail
+/// and properly queries the model for variable names, producing the same output as [`LinExpr::to_string_with_names`]
+/// and [`QuadExpr::to_string_with_names`]. Because querying the `VarName` of a variable can fail
 /// (for example if the model hasn't been updated since the variable was added or the `.attach(...)` was
 /// called with the wrong model), a formatting error can occur.
 ///
@@ -722,9 +847,9 @@ pub struct Attached<'a, T> {
 /// Output:
 /// ```console
 /// x[0]
-/// x[1] + x[0]
-/// x[4] + x[1] + x[3] + x[2] ≥ x[0]
-/// x[4] + x[1] + x[0] + x[3] + x[2] ∈ [0, 1]
+/// x[0] + x[1]
+/// x[1] + x[2] + x[3] + x[4] ≥ x[0]
+/// x[0] + x[1] + x[2] + x[3] + x[4] ∈ [0, 1]
 /// ```
 pub trait AttachModel {
     /// Attach a model reference to this object for formatting with [`Debug`]
@@ -752,6 +877,38 @@ fn float_fmt_helper(x: f64, ignore_val: f64) -> (Option<f64>, bool) {
     }
 }
 
+/// Append the sign of the next term in a sum to `s`; the leading term only gets a sign if it is negative.
+fn push_sign(s: &mut String, x: f64) {
+    match (s.is_empty(), x < 0.0) {
+        (true, true) => s.push('-'),
+        (true, false) => {}
+        (false, true) => s.push_str(" - "),
+        (false, false) => s.push_str(" + "),
+    }
+}
+
+/// Append `a name` to the sum in `s`, omitting the coefficient if it is `1` or `-1`.
+fn push_named_term(s: &mut String, a: f64, name: &str) {
+    push_sign(s, a);
+    let (coeff, _) = float_fmt_helper(a.abs(), 1.0);
+    if let Some(coeff) = coeff {
+        write!(s, "{coeff} ").unwrap();
+    }
+    s.push_str(name);
+}
+
+/// Append the constant term to the sum in `s`, omitting it if it is zero (unless the sum is otherwise empty).
+fn push_named_constant(s: &mut String, offset: f64) {
+    match float_fmt_helper(offset, 0.0) {
+        (Some(c), _) => {
+            push_sign(s, offset);
+            write!(s, "{c}").unwrap();
+        }
+        (None, _) if s.is_empty() => s.push('0'),
+        (None, _) => {}
+    }
+}
+
 impl From<Error> for fmt::Error {
     fn from(err: Error) -> fmt::Error {
         eprintln!("fmt error cause by: {err}");
@@ -761,97 +918,22 @@ impl From<Error> for fmt::Error {
 
 impl fmt::Debug for Attached<'_, LinExpr> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        if self.inner.is_empty() {
-            return f.write_str("<empty LinExpr>");
-        }
-
-        let (offset, positive) = float_fmt_helper(self.inner.get_offset(), 0.0);
-
-        let mut is_first_term = false;
-        if let Some(offset) = offset {
-            write!(f, "{}", if positive { offset } else { -offset })?;
-        } else {
-            is_first_term = true;
-        }
-
-        for (var, &coeff) in self.inner.iter_terms() {
-            let varname = self.model.get_obj_attr(attr::VarName, var)?;
-            let (coeff, positive) = float_fmt_helper(coeff, 1.0);
-
-            // write the operator with the previous term
-            if is_first_term {
-                is_first_term = false;
-                if !positive {
-                    f.write_char('-')?;
-                }
-            } else {
-                f.write_str(if positive { " + " } else { " - " })?;
-            }
-            if let Some(coeff) = coeff {
-                write!(f, "{coeff} ")?;
-            }
-            f.write_str(&varname)?;
-        }
-        Ok(())
+        f.write_str(&self.inner.to_string_with_names(self.model)?)
     }
 }
 
 impl fmt::Debug for Attached<'_, QuadExpr> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        if self.inner.is_empty() {
-            return f.write_str("<empty QuadExpr>");
-        }
-
-        let mut is_first_term = false;
-        if self.inner.linexpr.is_empty() {
-            is_first_term = true;
-        } else {
-            self.inner.linexpr.attach(self.model).fmt(f)?;
-        }
-
-        for ((x, y), &coeff) in &self.inner.qcoeffs {
-            let xname = self.model.get_obj_attr(attr::VarName, x)?;
-            let yname = self.model.get_obj_attr(attr::VarName, y)?;
-            let (coeff, positive) = float_fmt_helper(coeff, 1.0);
-            if is_first_term {
-                is_first_term = false;
-                if !positive {
-                    f.write_char('-')?;
-                }
-            } else {
-                f.write_str(if positive { " + " } else { " - " })?;
-            }
-            if let Some(coeff) = coeff {
-                write!(f, "{coeff} ")?;
-            }
-            write!(f, "{xname}*{yname}")?;
-        }
-        Ok(())
+        f.write_str(&self.inner.to_string_with_names(self.model)?)
     }
 }
 
 impl fmt::Debug for Attached<'_, Expr> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        use self::Expr::*;
-        match &self.inner {
-            Constant(a) => f.write_str(&a.to_string()),
-            Term(a, x) => {
-                let varname = self.model.get_obj_attr(attr::VarName, x)?;
-                if (a - 1.0).abs() >= f64::EPSILON {
-                    write!(f, "{a} ")?;
-                }
-                f.write_str(&varname)
-            }
-            QTerm(a, x, y) => {
-                let xname = self.model.get_obj_attr(attr::VarName, x)?;
-                let yname = self.model.get_obj_attr(attr::VarName, y)?;
-                if (a - 1.0).abs() >= f64::EPSILON {
-                    write!(f, "{a} ")?;
-                }
-                write!(f, "{xname}*{yname}")
-            }
-            Linear(e) => e.attach(self.model).fmt(f),
-            Quad(e) => e.attach(self.model).fmt(f),
+        match self.inner {
+            Expr::Linear(e) => e.attach(self.model).fmt(f),
+            Expr::Quad(e) => e.attach(self.model).fmt(f),
+            e => e.clone().into_quadexpr().attach(self.model).fmt(f),
         }
     }
 }
@@ -883,6 +965,125 @@ mod tests {
     }
   }
 
+    #[test]
+    fn from_term_vec() {
+        make_model_with_vars!(model, x, y);
+        let terms = vec![(x, 1.0), (y, 2.0), (x, 3.0)];
+        let e: LinExpr = terms.as_slice().into();
+        let e2: LinExpr = terms.into();
+        for e in [e, e2].iter() {
+            assert_eq!(e.num_terms(), 2);
+            assert_eq!(e.coeff[&x], 4.0);
+            assert_eq!(e.coeff[&y], 2.0);
+            assert_eq!(e.get_offset(), 0.0);
+        }
+
+        let e: QuadExpr = vec![((x, y), 1.0), ((y, x), 2.0), ((x, x), 1.5)].into();
+        assert_eq!(e.num_qterms(), 2);
+        assert_eq!(e.qcoeffs[&(x, y)], 3.0);
+        assert_eq!(e.qcoeffs[&(x, x)], 1.5);
+        assert_eq!(e.num_terms(), 0);
+    }
+
+    #[test]
+    fn sorted_iteration() {
+        make_model_with_vars!(model, x, y, z, w);
+        let e1: Expr = 2 * x + 3 * y + z + 4 * w + 5 * (x * y) + z * w + 2 * (x * z);
+        let e2: Expr = 2 * (x * z) + z * w + 4 * w + z + 5 * (x * y) + 3 * y + 2 * x;
+        let e1 = e1.into_quadexpr();
+        let e2 = e2.into_quadexpr();
+
+        let terms: Vec<_> = e1.affine_part().iter_terms_sorted().collect();
+        assert_eq!(
+            terms,
+            e2.affine_part().iter_terms_sorted().collect::<Vec<_>>()
+        );
+        let vars: Vec<_> = terms.iter().map(|(&v, _)| v).collect();
+        assert_eq!(vars, [x, y, z, w]);
+
+        let qterms: Vec<_> = e1.iter_qterms_sorted().collect();
+        assert_eq!(qterms, e2.iter_qterms_sorted().collect::<Vec<_>>());
+        let qvars: Vec<_> = qterms.iter().map(|(&vv, _)| vv).collect();
+        assert_eq!(qvars, [(x, y), (x, z), (z, w)]);
+    }
+
+    #[test]
+    fn overlapping_terms_merged() {
+        make_model_with_vars!(model, x, y, z);
+        let a: Expr = 3 * x + 2 * y;
+        let b: Expr = x + 2 * y + z;
+
+        let e = (a.clone() - b.clone()).into_linexpr().unwrap();
+        assert_eq!(e.num_terms(), 2);
+        let terms: Vec<_> = e.iter_terms_sorted().map(|(&v, &c)| (v, c)).collect();
+        assert_eq!(terms, [(x, 2.0), (z, -1.0)]);
+
+        let e = (a + b).into_linexpr().unwrap();
+        assert_eq!(e.num_terms(), 3);
+
+        let e: Expr = 3 * x - x - 2 * x + y;
+        assert_eq!(e.into_linexpr().unwrap().num_terms(), 1);
+        assert_eq!((x - x).into_linexpr().unwrap().num_terms(), 0);
+
+        let e: Expr = x * y + 0.1 * x + 0.2 * x - 0.3 * x;
+        let e = e.into_quadexpr();
+        assert_eq!(e.num_terms(), 0);
+        assert_eq!(e.num_qterms(), 1);
+    }
+
+    #[test]
+    fn grb_sum_quadexprs() {
+        make_model_with_vars!(model, x, y, z);
+        let exprs: [Expr; 3] = [
+            x * x + 2 * (x * y) + z,
+            y * y + 3 * (y * x) - z + 1,
+            z * z + x,
+        ];
+        let exprs: Vec<QuadExpr> = exprs.iter().cloned().map(Expr::into_quadexpr).collect();
+        let e = exprs.grb_sum().into_quadexpr();
+        assert_eq!(e.num_qterms(), 4);
+        assert_eq!(e.num_terms(), 1);
+        assert_eq!(e.affine_part().get_offset(), 1.0);
+        let qterms: Vec<_> = e.iter_qterms_sorted().map(|(&vv, &a)| (vv, a)).collect();
+        assert_eq!(
+            qterms,
+            [((x, x), 1.0), ((x, y), 5.0), ((y, y), 1.0), ((z, z), 1.0)]
+        );
+
+        let squares = [x, y, z].iter().map(|&v| (v * v).into_quadexpr()).grb_sum();
+        assert_eq!(squares.into_quadexpr().num_qterms(), 3);
+    }
+
+    #[test]
+    fn to_string_with_names() {
+        make_model_with_vars!(model, x, y, z);
+        let e: Expr = 3 * x + 2 * y - z + 5;
+        let e = e.into_linexpr().unwrap();
+        assert_eq!(e.to_string_with_names(&model).unwrap(), "3 x + 2 y - z + 5");
+        let e: Expr = 1.5 - 2 * y - x;
+        let e = e.into_linexpr().unwrap();
+        assert_eq!(e.to_string_with_names(&model).unwrap(), "-x - 2 y + 1.5");
+        assert_eq!(LinExpr::new().to_string_with_names(&model).unwrap(), "0");
+
+        let e: Expr = x * x + 2 * (x * y) - y - 1;
+        assert_eq!(
+            e.into_quadexpr().to_string_with_names(&model).unwrap(),
+            "-y + x^2 + 2 x*y - 1"
+        );
+        let e: Expr = -0.5 * (z * y);
+        let e = e.into_quadexpr();
+        assert_eq!(e.to_string_with_names(&model).unwrap(), "-0.5 y*z");
+
+        // `Debug` on attached expressions uses the same formatting
+        assert_eq!(format!("{:?}", e.attach(&model)), "-0.5 y*z");
+        let e: Expr = 2 * y + x - 4;
+        assert_eq!(format!("{:?}", e.attach(&model)), "x + 2 y - 4");
+        assert_eq!(format!("{:?}", LinExpr::new().attach(&model)), "0");
+        assert_eq!(format!("{:?}", Expr::Constant(-2.0).attach(&model)), "-2");
+        assert_eq!(format!("{:?}", (x * x).attach(&model)), "x^2");
+        assert_eq!(format!("{:?}", x.attach(&model)), "x");
+    }
+
     #[test]
     fn simple() {
         make_model_with_vars!(model, x, y);
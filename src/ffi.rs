@@ -34,3 +34,43 @@ cfg_if::cfg_if! {
         compile_error!("bug: one of the above feature flags should have been hit.");
     }
 }
+
+// Routines which are not (yet) bound by `grb-sys2`.
+extern "C" {
+    /// Available since Gurobi 9.5.  The returned string points into internal Gurobi data
+    /// structures and must be copied before the next call to a Gurobi routine.
+    pub fn GRBgetjsonsolution(model: *mut GRBmodel, buff: *mut c_str) -> c_int;
+
+    pub fn GRBpresolvemodel(model: *mut GRBmodel, presolvedP: *mut *mut GRBmodel) -> c_int;
+}
+
+// General nonlinear constraints, new in Gurobi 12.
+#[cfg(feature = "gurobi12")]
+extern "C" {
+    pub fn GRBaddgenconstrNL(
+        model: *mut GRBmodel,
+        name: c_str,
+        resvar: c_int,
+        nnodes: c_int,
+        opcode: *mut c_int,
+        data: *mut f64,
+        parent: *mut c_int,
+    ) -> c_int;
+}
+
+/// Opaque batch object used by the batch optimization routines.
+#[cfg(feature = "batch")]
+#[repr(C)]
+pub struct GRBbatch {
+    _private: [u8; 0],
+}
+
+// Batch optimization routines (require a Cluster Manager or Instant Cloud license).
+#[cfg(feature = "batch")]
+extern "C" {
+    pub fn GRBoptimizebatch(model: *mut GRBmodel, batch_id: *mut c_char) -> c_int;
+    pub fn GRBgetbatch(env: *mut GRBenv, batch_id: c_str, batch: *mut *mut GRBbatch) -> c_int;
+    pub fn GRBgetbatchintattr(batch: *mut GRBbatch, attrname: c_str, value: *mut c_int) -> c_int;
+    pub fn GRBgetbatchjsonsolution(batch: *mut GRBbatch, json: *mut c_str) -> c_int;
+    pub fn GRBfreebatch(batch: *mut GRBbatch) -> c_int;
+}
@@ -27,13 +27,18 @@ pub fn version() -> (i32, i32, i32) {
 /// | `name`   | Anything that implements `AsRef<str>` (&str, String, etc) | `name`                      |
 /// | `obj`    | Anything that can be cast to a `f64`                      | `obj`                       |
 /// | `bounds` | A range expression, see below                             | `ub` & `lb`                 |
+/// | `start`  | Anything that can be cast to a `f64`                      | none, see below             |
 ///
 /// The `bounds` argument takes a value of the form `LB..UB` where `LB` and `UB` are the upper and lower bounds of the variable.
 ///  `LB` and `UB` can be   left off as well, so `..UB` (short for `-INFINITY..UB`), `LB..` (short for `LB..INFINITY`) and `..`
-/// are also valid values.
-///
+/// are also valid values.  Both bounds are inclusive, and `LB..=UB` may be used in place of `LB..UB`.
 ///
+/// The `start` argument sets the `Start` attribute of the new variable (a MIP start value) using [`Model::set_start_deferred`], so in lazy update mode the value is only set once the variable is added by the next [`Model::update`].
+/// An [`Error::InvalidArgument`] is returned if the start value lies outside the bounds, in which case no variable is added.
 ///
+/// [`Model::set_start_deferred`]: struct.Model.html#method.set_start_deferred
+/// [`Model::update`]: struct.Model.html#method.update
+/// [`Error::InvalidArgument`]: enum.Error.html#variant.InvalidArgument
 /// [`Model::add_var`]: struct.Model.html#method.add_var
 /// [`VarType`]: enum.VarType.html
 /// ```
@@ -42,6 +47,7 @@ pub fn version() -> (i32, i32, i32) {
 /// add_var!(model, Continuous, name: "name", obj: 0.0, bounds: -10..10)?;
 /// add_var!(model, Integer, bounds: 0..)?;
 /// add_var!(model, Continuous, name: &format!("X[{}]", 42))?;
+/// add_var!(model, Integer, bounds: 0..10, start: 3)?;
 /// # Ok::<(), grb::Error>(())
 /// ```
 ///
@@ -108,7 +114,8 @@ pub use grb_macro::add_intvar;
 /// c!( EXPR in .. )
 /// ```
 /// where `EXPR` is a valid expression, like `LHS` and `RHS` above.  Additionally, `EXPR` must be linear,
-/// although this is not checked at compile-time.
+/// although this is not checked at compile-time.  Both bounds of a range constraint are inclusive,
+/// so `LB..=UB` and `..=UB` may be used in place of `LB..UB` and `..UB`.
 ///
 /// `LB` and `UB` can be any expression that evaluates to type that can be cast to a `f64` using
 /// the `as` operator. For example, the following are valid (variables have the meaning as above):
@@ -118,6 +125,23 @@ pub use grb_macro::add_intvar;
 ///   c!( x - y + 2*z in 0..200 );
 ///   c!( x - y + 2*z in 1.. );
 ///   c!( x - y in (1.0/3.0)..(1<<4));
+///   c!( x - y in 0..=10 );
+/// # }
+/// ```
+///
+/// ## Indicator constraints
+/// To create an `IndicatorExpr` object for an indicator constraint, use the syntax
+/// ```text
+/// c!( BINVAR == 1 >> (LHS CMP RHS) )
+/// c!( BINVAR == 0 >> (LHS CMP RHS) )
+/// ```
+/// where `BINVAR` is a binary `Var`, and `LHS CMP RHS` is a linear inequality constraint as above.  The constraint
+/// must be enclosed in brackets.  The resulting object is passed to [`Model::add_indicator`](crate::Model::add_indicator):
+/// ```
+/// # use grb::prelude::*;
+/// # fn f(b: Var, x: Var, y: Var){
+///   c!( b == 1 >> (x + y <= 4) );
+///   c!( b == 0 >> (x - y == 0) );
 /// # }
 /// ```
 ///
@@ -127,12 +151,18 @@ pub use grb_macro::c;
 // public modules
 #[path = "attribute.rs"]
 pub mod attribute;
+#[cfg(feature = "batch")]
+#[path = "batch.rs"]
+pub mod batch;
 #[path = "callback.rs"]
 pub mod callback;
 #[path = "constr.rs"]
 pub mod constr;
 #[path = "expr.rs"]
 pub mod expr;
+#[cfg(feature = "gurobi12")]
+#[path = "nl.rs"]
+pub mod nl;
 #[path = "parameter.rs"]
 pub mod parameter;
 #[path = "prelude.rs"]
@@ -149,13 +179,13 @@ pub use parameter::param;
 #[path = "constants.rs"]
 pub(crate) mod constants;
 pub use constants::{
-    ConstrSense, GenConstrType, ModelSense, RelaxType, SOSType, Status, VarType,
-    GRB_INFINITY as INFINITY,
+    ConstrSense, GenConstrType, IisForce, ModelFormat, ModelSense, RelaxType, SOSType, Status,
+    VarType, GRB_INFINITY as INFINITY, GRB_NEG_INFINITY as NEG_INFINITY,
 };
 
 #[path = "env.rs"]
 mod env;
-pub use env::{EmptyEnv, Env};
+pub use env::{EmptyEnv, Env, EnvBuilder};
 
 #[path = "error.rs"]
 mod error;
@@ -163,11 +193,14 @@ pub use error::{Error, Result};
 
 #[path = "model.rs"]
 mod model;
-pub use model::{AsyncHandle, AsyncModel, Model};
+pub use model::{
+    AsyncHandle, AsyncModel, FeasRelaxSlacks, Iis, Model, ObjectiveBuilder, SolveReport,
+    SolveStats, SparseVec, SubsetSpec, Violation,
+};
 
 #[path = "model_object.rs"]
 pub(crate) mod model_object;
-pub use model_object::{Constr, GenConstr, ModelObject, QConstr, Var, SOS};
+pub use model_object::{Constr, GenConstr, ModelObject, NamedModelObject, QConstr, Var, SOS};
 
 #[path = "util.rs"]
 pub(crate) mod util;
@@ -1,21 +1,24 @@
 use std::borrow::Borrow;
+use std::collections::{HashMap, HashSet};
+use std::convert::{TryFrom, TryInto};
 use std::ffi::CString;
 use std::mem::transmute;
 use std::path::Path;
 use std::ptr::{null, null_mut};
 use std::sync::atomic::{AtomicU32, Ordering};
 
-use crate::attribute::{ModelAttrGet, ModelAttrSet, ObjAttrGet, ObjAttrSet};
-use crate::callback::{callback_wrapper, UserCallbackData};
-use crate::constr::{IneqExpr, RangeExpr};
+use crate::attribute::{AttrInfo, ModelAttrGet, ModelAttrSet, ObjAttrGet, ObjAttrSet, RawAttr};
+use crate::callback::{callback_wrapper, IncumbentTracker, UserCallbackData};
+use crate::constr::{IndicatorExpr, IneqExpr, RangeExpr};
 use crate::expr::{LinExpr, QuadExpr};
 use crate::ffi;
 use crate::ffi::c_int;
-use crate::model_object::IdxManager;
+use crate::model_object::{IdxManager, NamedModelObject};
 use crate::parameter::{ParamGet, ParamSet};
 use crate::prelude::*;
 use crate::util::AsPtr;
-use crate::{Error, Result};
+use crate::{Error, GenConstrType, ModelFormat, Result};
+use cstr_enum::AsCStr;
 
 /// Gurobi Model object.
 ///
@@ -30,6 +33,10 @@ pub struct Model {
     pub(crate) genconstrs: IdxManager<GenConstr>,
     pub(crate) qconstrs: IdxManager<QConstr>,
     pub(crate) sos: IdxManager<SOS>,
+    // whether the user MIP start was used, according to the log of the last solve (see `start_was_used`)
+    mip_start_used: Option<bool>,
+    // start values for variables which were still pending when set (see `set_start_deferred`)
+    pending_starts: Vec<(Var, f64)>,
 }
 
 macro_rules! impl_object_list_getter {
@@ -239,7 +246,8 @@ impl Model {
     /// that the environment the user supplies is copied,  but must be assumed for other
     /// Gurobi routines that create new `GRBmodel`s like
     /// [`GRBfeasrelax`](https://www.gurobi.com/documentation/9.1/refman/c_feasrelax.html),
-    /// [`GRBfixmodel`](https://www.gurobi.com/documentation/9.1/refman/c_fixmodel.html)
+    /// [`GRBfixmodel`](https://www.gurobi.com/documentation/9.1/refman/c_fixmodel.html),
+    /// [`GRBpresolvemodel`](https://www.gurobi.com/documentation/9.1/refman/c_presolvemodel.html)
     /// and [`GRBreadmodel`](https://www.gurobi.com/documentation/9.1/refman/c_readmodel.html)
     /// This assumption is necessary to prevent a double free when a `Model` object is dropped,
     /// which frees the `GRBModel` and triggers the drop of a `Env`, which in turn
@@ -265,6 +273,8 @@ impl Model {
             genconstrs: IdxManager::new(id),
             qconstrs: IdxManager::new(id),
             sos: IdxManager::new(id),
+            mip_start_used: None,
+            pending_starts: Vec::new(),
         };
 
         let nvars = model.get_attr(attr::NumVars)?;
@@ -378,6 +388,67 @@ impl Model {
         Model::from_raw(&self.env, fixed)
     }
 
+    /// Perform presolve on the model, returning the presolved model.  The model itself is not modified, apart from
+    /// applying any pending changes.
+    ///
+    /// The presolved model has its own copy of the environment.  Its variables and constraints do not
+    /// correspond to those of the original model, although their names are preserved.
+    ///
+    /// # Examples
+    /// ```
+    /// # use grb::prelude::*;
+    /// let mut m = Model::new("model")?;
+    /// let x = add_ctsvar!(m, bounds: 0..10)?;
+    /// let y = add_ctsvar!(m, bounds: 0..10)?;
+    /// m.add_constr("fix", c!(y == 2))?;
+    /// m.add_constr("c", c!(x + y <= 5))?;
+    /// m.set_objective(x + y, Maximize)?;
+    /// let presolved = m.presolve()?;
+    /// assert!(presolved.get_attr(attr::NumVars)? < 2);
+    /// # Ok::<(), grb::Error>(())
+    /// ```
+    pub fn presolve(&mut self) -> Result<Model> {
+        self.update()?;
+        let mut presolved: *mut ffi::GRBmodel = null_mut();
+        self.check_apicall(unsafe { ffi::GRBpresolvemodel(self.ptr, &mut presolved) })?;
+        Model::from_raw(&self.env, presolved)
+    }
+
+    /// Compute the bound given by the LP relaxation of the model.
+    ///
+    /// A relaxed copy of the model is created with `GRBrelaxmodel`, in which integer variables are made continuous
+    /// and SOS and general constraints are dropped, and is then solved.  The model itself is not modified,
+    /// apart from applying any pending changes.
+    ///
+    /// # Examples
+    /// ```
+    /// # use grb::prelude::*;
+    /// let mut m = Model::new("model")?;
+    /// let x = add_intvar!(m, bounds: 0..10)?;
+    /// m.add_constr("c", c!(2*x <= 5))?;
+    /// m.set_objective(x, Maximize)?;
+    /// assert_eq!(m.lp_relaxation_bound()?, 2.5);
+    /// # Ok::<(), grb::Error>(())
+    /// ```
+    ///
+    /// # Errors
+    /// - [`Error::DataNotAvailable`] if the relaxation could not be solved to optimality, for example because it is
+    ///   infeasible or unbounded.
+    /// - [`Error::FromAPI`] if a Gurobi API error occurs.
+    pub fn lp_relaxation_bound(&mut self) -> Result<f64> {
+        self.update()?;
+        let mut relaxed: *mut ffi::GRBmodel = null_mut();
+        self.check_apicall(unsafe { ffi::GRBrelaxmodel(self.ptr, &mut relaxed) })?;
+        let mut relaxed = Model::from_raw(&self.env, relaxed)?;
+        relaxed.optimize()?;
+        match relaxed.status()? {
+            Status::Optimal => relaxed.get_attr(attr::ObjVal),
+            status => Err(Error::DataNotAvailable(format!(
+                "LP relaxation could not be solved to optimality (status {status:?})"
+            ))),
+        }
+    }
+
     /// Get shared reference to the environment associated with the model.
     pub fn get_env(&self) -> &Env {
         &self.env
@@ -406,15 +477,101 @@ impl Model {
     /// # Ok::<(), grb::Error>(())
     /// ```
     pub fn update(&mut self) -> Result<()> {
+        self.flush_deferred_removals::<Var>()?;
+        self.flush_deferred_removals::<Constr>()?;
+        self.flush_deferred_removals::<GenConstr>()?;
+        self.flush_deferred_removals::<QConstr>()?;
+        self.flush_deferred_removals::<SOS>()?;
         self.vars.update();
         self.constrs.update();
         self.genconstrs.update();
         self.qconstrs.update();
         self.sos.update();
         self.check_apicall(unsafe { ffi::GRBupdatemodel(self.ptr) })?;
+        // The queued start values belong to variables which Gurobi only knows about after the update above, so they
+        // can't be set any earlier.  Setting them is itself a pending change, hence the second update.
+        if !self.pending_starts.is_empty() {
+            self.set_start(self.pending_starts.clone())?;
+            self.pending_starts.clear();
+            self.check_apicall(unsafe { ffi::GRBupdatemodel(self.ptr) })?;
+        }
         Ok(())
     }
 
+    /// Apply all pending changes with a final call to [`Model::update`] and then free the model.
+    ///
+    /// When a `Model` is dropped, any changes which have not yet been applied are silently
+    /// discarded, and since `Drop` cannot fail, there is no way to observe errors which would have
+    /// occurred.  This method gives "flush on close" semantics instead, propagating any error
+    /// returned by the final update.
+    ///
+    /// # Examples
+    /// ```
+    /// # use grb::prelude::*;
+    /// let mut m = Model::new("model")?;
+    /// let x = add_ctsvar!(m)?;
+    /// m.add_constr("c", c!(x <= 1))?;
+    /// m.close()?;
+    /// # Ok::<(), grb::Error>(())
+    /// ```
+    pub fn close(mut self) -> Result<()> {
+        self.update()
+    }
+
+    /// Build part of a model in bulk.  The model is placed in lazy update mode (`UpdateMode = 0`)
+    /// while `build` runs, and a single [`Model::update`] is performed afterwards, regardless of
+    /// the `UpdateMode` parameter of the environment.
+    ///
+    /// This is the recommended way to add large numbers of variables and constraints, since
+    /// Gurobi only needs to process the changes once.  Inside the closure, newly added objects are
+    /// *pending*, so they can be used to build constraints, but their attributes cannot be queried
+    /// until after the update.  The original `UpdateMode` is restored before the update, even if
+    /// `build` returns an error.
+    ///
+    /// # Examples
+    /// ```
+    /// # use grb::prelude::*;
+    /// let mut m = Model::new("model")?;
+    /// let x = m.build_scope(|m| {
+    ///   let x: Vec<_> = (0..100).map(|_| add_binvar!(m)).collect::<grb::Result<_>>()?;
+    ///   m.add_constr("sum", c!(x.iter().grb_sum() <= 10))?;
+    ///   Ok(x)
+    /// })?;
+    /// assert_eq!(m.num_pending_changes(), 0);
+    /// assert_eq!(m.get_obj_attr(attr::UB, &x[0])?, 1.0);
+    /// # Ok::<(), grb::Error>(())
+    /// ```
+    pub fn build_scope<T>(&mut self, build: impl FnOnce(&mut Model) -> Result<T>) -> Result<T> {
+        let update_mode = self.env.get(param::UpdateMode)?;
+        self.env.set(param::UpdateMode, 0)?;
+        let result = build(self);
+        self.env.set(param::UpdateMode, update_mode)?;
+        let output = result?;
+        self.update()?;
+        Ok(output)
+    }
+
+    /// Retrieve the number of queued model object additions and removals which will be applied at the
+    /// next call to [`Model::update`].  This is intended as a diagnostic tool.
+    ///
+    /// # Examples
+    /// ```
+    /// # use grb::prelude::*;
+    /// let mut m = Model::new("model")?;
+    /// let x = add_ctsvar!(m)?;
+    /// assert_eq!(m.num_pending_changes(), 1);
+    /// m.update()?;
+    /// assert_eq!(m.num_pending_changes(), 0);
+    /// # Ok::<(), grb::Error>(())
+    /// ```
+    pub fn num_pending_changes(&self) -> usize {
+        self.vars.num_pending()
+            + self.constrs.num_pending()
+            + self.genconstrs.num_pending()
+            + self.qconstrs.num_pending()
+            + self.sos.num_pending()
+    }
+
     /// Query update mode. See <https://docs.gurobi.com/projects/optimizer/en/current/reference/parameters.html#parameterupdatemode>
     fn update_mode_lazy(&self) -> Result<bool> {
         //  0 => pending until update() or optimize() called.
@@ -431,11 +588,18 @@ impl Model {
         F: Callback,
     {
         self.update()?;
+        self.mip_start_used = None;
         let nvars = self.get_attr(attr::NumVars)? as usize;
+        let incumbent = if callback.track_incumbent() {
+            Some(IncumbentTracker::new(self.get_attr(attr::ModelSense)?))
+        } else {
+            None
+        };
         let mut usrdata = UserCallbackData {
             model: self,
             cb_obj: callback,
             nvars,
+            incumbent,
         };
 
         unsafe {
@@ -455,6 +619,7 @@ impl Model {
     /// Optimize the model synchronously.  This method will always trigger a [`Model::update`].
     pub fn optimize(&mut self) -> Result<()> {
         self.update()?;
+        self.mip_start_used = None;
         self.check_apicall(unsafe { ffi::GRBoptimize(self.ptr) })
     }
 
@@ -472,8 +637,81 @@ impl Model {
         self.call_with_callback(ffi::GRBoptimize, callback)
     }
 
+    /// Optimize the model with a callback, taking the callback by value.  The callback is handed
+    /// back once optimisation finishes, so any state it accumulated can be inspected afterwards, or it can be
+    /// passed to a subsequent call.  Otherwise, this is identical to [`Model::optimize_with_callback`].
+    ///
+    /// # Examples
+    /// ```
+    /// # use grb::prelude::*;
+    /// # use grb::callback::CbResult;
+    /// struct CountCalls(usize);
+    ///
+    /// impl Callback for CountCalls {
+    ///   fn callback(&mut self, _w: Where) -> CbResult {
+    ///     self.0 += 1;
+    ///     Ok(())
+    ///   }
+    /// }
+    ///
+    /// let mut m = Model::new("model")?;
+    /// let x = add_intvar!(m, bounds: 0..10)?;
+    /// m.set_objective(x, Maximize)?;
+    /// let counter = m.optimize_with(CountCalls(0))?;
+    /// println!("callback was called {} times", counter.0);
+    /// # Ok::<(), grb::Error>(())
+    /// ```
+    ///
+    /// # Panics
+    /// This function panics if Gurobi errors on clearing the callback.
+    pub fn optimize_with<F>(&mut self, mut callback: F) -> Result<F>
+    where
+        F: Callback,
+    {
+        self.call_with_callback(ffi::GRBoptimize, &mut callback)?;
+        Ok(callback)
+    }
+
+    /// Optimize the model, capturing the solver log of this call in a `String` instead of printing it to the
+    /// console.  The log lines are collected with a message callback, and the `LogToConsole` parameter is
+    /// set to `0` for the duration of the call.  Returns the optimisation status and the captured log.
+    ///
+    /// Note that Gurobi produces no log output when the `OutputFlag` parameter is `0`, in which case the
+    /// captured log will be empty.
+    ///
+    /// # Panics
+    /// This function panics if Gurobi errors on clearing the callback.
+    pub fn optimize_capturing_log(&mut self) -> Result<(Status, String)> {
+        let log_to_console = self.get_param(param::LogToConsole)?;
+        self.set_param(param::LogToConsole, 0)?;
+        let mut log = String::new();
+        let mut callback = |w: Where| -> crate::callback::CbResult {
+            if let Where::Message(ctx) = w {
+                log.push_str(&ctx.message()?);
+                log.push('\n');
+            }
+            Ok(())
+        };
+        let res = self.call_with_callback(ffi::GRBoptimize, &mut callback);
+        self.set_param(param::LogToConsole, log_to_console)?;
+        res?;
+        self.mip_start_used = parse_mip_start_used(&log);
+        Ok((self.status()?, log))
+    }
+
+    /// Returns whether the user MIP start (the `Start` attribute of the variables) was used in the last solve.
+    ///
+    /// Gurobi has no attribute for this, so it is determined from the log captured by the most recent call to
+    /// [`Model::optimize_capturing_log`]. `Some(true)` means the start was loaded as an incumbent and `Some(false)`
+    /// means it was rejected or produced no new incumbent.  Returns `None` if the model was last optimised by other
+    /// means, or if the log does not mention a user MIP start (for example because none was given, or because
+    /// the `OutputFlag` parameter is `0`).
+    pub fn start_was_used(&self) -> Result<Option<bool>> {
+        Ok(self.mip_start_used)
+    }
+
     /// Compute an Irreducible Inconsistent Subsystem (IIS) of the model.  The constraints in the IIS can be identified
-    /// by checking their `IISConstr` attribute
+    /// by checking their `IISConstr` attribute, or all members of the IIS can be retrieved with [`Model::get_iis`].
     ///
     /// # Example
     /// ```
@@ -495,6 +733,74 @@ impl Model {
         self.check_apicall(unsafe { ffi::GRBcomputeIIS(self.ptr) })
     }
 
+    /// Retrieve the members of the IIS computed by the last call to [`Model::compute_iis`] (or one of its variants),
+    /// by reading the `IISConstr`, `IISQConstr`, `IISSOS`, `IISGenConstr`, `IISLB` and `IISUB` attributes.
+    ///
+    /// # Example
+    /// ```
+    /// # use grb::prelude::*;
+    /// let mut m = Model::new("model")?;
+    /// let x = add_ctsvar!(m, bounds: 0..1)?;
+    /// let c = m.add_constr("c", c!(x >= 2))?;
+    /// m.update()?;
+    /// m.compute_iis()?;
+    /// let iis = m.get_iis()?;
+    /// assert_eq!(iis.constrs, [c]);
+    /// assert_eq!(iis.ub_vars, [x]);
+    /// # Ok::<(), grb::Error>(())
+    /// ```
+    ///
+    /// # Errors
+    /// - [`Error::ModelUpdateNeeded`] if a model update is needed.
+    /// - [`Error::FromAPI`] if no IIS is available.
+    pub fn get_iis(&self) -> Result<Iis> {
+        fn members<O, A>(model: &Model, attr: A, objs: &[O]) -> Result<Vec<O>>
+        where
+            O: ModelObject,
+            A: ObjAttrGet<O, i32>,
+        {
+            let flags = model.get_obj_attr_batch(attr, objs.iter().copied())?;
+            Ok(objs
+                .iter()
+                .zip(flags)
+                .filter_map(|(&o, flag)| if flag > 0 { Some(o) } else { None })
+                .collect())
+        }
+
+        let vars = self.get_vars()?;
+        Ok(Iis {
+            constrs: members(self, attr::IISConstr, self.get_constrs()?)?,
+            qconstrs: members(self, attr::IISQConstr, self.get_qconstrs()?)?,
+            sos: members(self, attr::IISSOS, self.get_sos()?)?,
+            genconstrs: members(self, attr::IISGenConstr, self.get_genconstrs()?)?,
+            lb_vars: members(self, attr::IISLB, vars)?,
+            ub_vars: members(self, attr::IISUB, vars)?,
+        })
+    }
+
+    /// Compute an IIS of the model, restricting which linear constraints may appear in it.
+    ///
+    /// The `IISConstrForce` attribute of each given constraint is set before the IIS is computed, so
+    /// constraints can be forced into ([`IisForce::Include`]) or out of ([`IisForce::Exclude`]) the IIS.
+    /// Constraints which are not given keep their current `IISConstrForce` value.  This is useful to
+    /// find conflicts among only a subset of the constraints.
+    ///
+    /// # Errors
+    /// - [`Error::ModelObjectPending`] if some constraints haven't yet been added to the model.
+    /// - [`Error::ModelObjectRemoved`] if some constraints have been removed from the model.
+    /// - [`Error::FromAPI`] if the model is feasible, or if no IIS exists with the given constraints excluded.
+    pub fn compute_iis_with_forces(
+        &mut self,
+        force: impl IntoIterator<Item = (Constr, IisForce)>,
+    ) -> Result<()> {
+        self.update()?;
+        self.set_obj_attr_batch(
+            attr::IISConstrForce,
+            force.into_iter().map(|(c, f)| (c, f as i32)),
+        )?;
+        self.compute_iis()
+    }
+
     /// Compute an IIS of the model with a callback.  Only the only variant of [`Where`] will be [`Where::IIS`].
     pub fn compute_iis_with_callback<F>(&mut self, callback: &mut F) -> Result<()>
     where
@@ -514,11 +820,33 @@ impl Model {
     /// (currently MIP starts, variable hints, branching priorities, lazy flags, and partition information). A value of 0 just
     /// discards the solution.
     ///
-    /// All solution information previously computed are discarded.
+    /// All solution information previously computed are discarded.  See also [`Model::reset_with`].
     pub fn reset(&self, clearall: i16) -> Result<()> {
         self.check_apicall(unsafe { ffi::GRBreset(self.ptr, clearall as ffi::c_int) })
     }
 
+    /// Reset the model to an unsolved state, discarding any previously computed solution information.
+    ///
+    /// If `clearall` is `true`, additional information that affects the solution process but not the actual model
+    /// (currently MIP starts, variable hints, branching priorities, lazy flags, and partition information) is also
+    /// discarded.
+    ///
+    /// # Example
+    /// ```
+    /// # use grb::prelude::*;
+    /// let mut m = Model::new("model")?;
+    /// let x = add_intvar!(m, bounds: 0..1, obj: 1)?;
+    /// m.set_param(param::OutputFlag, 0)?;
+    /// m.optimize()?;
+    /// assert_eq!(m.status()?, Status::Optimal);
+    /// m.reset_with(false)?;
+    /// assert_eq!(m.status()?, Status::Loaded);
+    /// # Ok::<(), grb::Error>(())
+    /// ```
+    pub fn reset_with(&self, clearall: bool) -> Result<()> {
+        self.check_apicall(unsafe { ffi::GRBreset(self.ptr, clearall as ffi::c_int) })
+    }
+
     /// Perform an automated search for parameter settings that improve performance on the model.
     /// See also references [on official
     /// manual](https://www.gurobi.com/documentation/6.5/refman/parameter_tuning_tool.html#sec:Tuning).
@@ -562,14 +890,192 @@ impl Model {
     /// JSON format. If your system has compression utilities installed (e.g., 7z or zip for Windows, and gzip,
     /// bzip2, or unzip for Linux or Mac OS), then the files can be compressed, so additional suffixes of `.gz`,
     /// `.bz2`, or `.7z` are accepted.
+    ///
+    /// In lazy update mode (`UpdateMode` parameter set to 0), objects removed with [`Model::remove`] are only deleted
+    /// from the Gurobi model by the next [`Model::update`], so they are still written if the model has not been
+    /// updated since they were removed.
     pub fn write(&self, filename: impl AsRef<Path>) -> Result<()> {
         let filename = crate::util::path_to_cstring(filename)?;
         self.check_apicall(unsafe { ffi::GRBwrite(self.ptr, filename.as_ptr()) })
     }
 
+    /// Export the model to an in-memory buffer in the given format.  The Gurobi C API can only write to files, so
+    /// the model is written to a temporary file (which is removed afterwards) and read back.  See [`Model::write`].
+    ///
+    /// # Examples
+    /// ```
+    /// # use grb::prelude::*;
+    /// use grb::ModelFormat;
+    /// let mut m = Model::new("model")?;
+    /// let x = add_ctsvar!(m, name: "x")?;
+    /// m.add_constr("c", c!(x <= 1))?;
+    /// m.update()?;
+    /// let lp = m.write_to_bytes(ModelFormat::Lp)?;
+    /// let copy = Model::from_bytes(&lp, ModelFormat::Lp, m.get_env())?;
+    /// assert_eq!(copy.get_attr(attr::NumConstrs)?, 1);
+    /// # Ok::<(), grb::Error>(())
+    /// ```
+    pub fn write_to_bytes(&self, format: ModelFormat) -> Result<Vec<u8>> {
+        let file = crate::util::TempFile::new(format.suffix())?;
+        self.write(file.path())?;
+        std::fs::read(file.path()).map_err(|e| crate::util::io_error(e, file.path()))
+    }
+
+    /// Read a model from an in-memory buffer in the given format, such as one produced by
+    /// [`Model::write_to_bytes`].  The data is written to a temporary file (which is removed afterwards), which is
+    /// then read with [`Model::from_file_with_env`].
+    pub fn from_bytes(data: &[u8], format: ModelFormat, env: &Env) -> Result<Model> {
+        let file = crate::util::TempFile::new(format.suffix())?;
+        std::fs::write(file.path(), data).map_err(|e| crate::util::io_error(e, file.path()))?;
+        Model::from_file_with_env(file.path(), env)
+    }
+
+    // check that `filename` has a `.bas` suffix, ignoring any compression suffix
+    fn check_basis_filename(filename: &Path) -> Result<()> {
+        let mut path = filename;
+        if let Some(ext) = path.extension() {
+            if ext == "gz" || ext == "bz2" || ext == "7z" {
+                path = path.file_stem().map(Path::new).unwrap_or(path);
+            }
+        }
+        if path.extension().is_some_and(|ext| ext == "bas") {
+            Ok(())
+        } else {
+            Err(Error::InvalidArgument(format!(
+                "Basis file {} must have a .bas suffix",
+                filename.display()
+            )))
+        }
+    }
+
+    /// Read an LP basis from a `.bas` file and apply it to the model.  The basis will be used as a
+    /// warm start by the next (simplex) solve.
+    ///
+    /// A basis is meaningless for a MIP model, so this method returns an error if the model has
+    /// integer variables or other discrete elements.
+    ///
+    /// # Errors
+    /// - [`Error::InvalidArgument`] if the filename does not end in `.bas` (optionally followed by a compression
+    ///   suffix), or the model is a MIP.
+    /// - [`Error::FromAPI`] if Gurobi fails to read the file.
+    pub fn read_basis(&mut self, filename: impl AsRef<Path>) -> Result<()> {
+        let filename = filename.as_ref();
+        Model::check_basis_filename(filename)?;
+        if self.is_mip()? {
+            return Err(Error::InvalidArgument(
+                "Cannot read a basis into a MIP model".to_owned(),
+            ));
+        }
+        self.read(filename)
+    }
+
+    /// Write the current LP basis to a `.bas` file.  A basis is only available after an LP has been
+    /// solved with a simplex algorithm (or barrier with crossover).
+    ///
+    /// # Errors
+    /// - [`Error::InvalidArgument`] if the filename does not end in `.bas` (optionally followed by a compression
+    ///   suffix).
+    /// - [`Error::FromAPI`] if no basis is available or Gurobi fails to write the file.
+    pub fn write_basis(&self, filename: impl AsRef<Path>) -> Result<()> {
+        let filename = filename.as_ref();
+        Model::check_basis_filename(filename)?;
+        self.write(filename)
+    }
+
+    /// Retrieve the current solution in Gurobi's [JSON solution format](https://docs.gurobi.com/projects/optimizer/en/current/reference/fileformats/jsonsolutionformat.html).
+    ///
+    /// This returns the same contents as writing a `.json` file with [`Model::write`], without the
+    /// need for a temporary file.
+    ///
+    /// # Errors
+    /// Returns [`Error::FromAPI`] if no solution information is available.
+    pub fn json_solution_string(&self) -> Result<String> {
+        let mut buf = null();
+        self.check_apicall(unsafe { ffi::GRBgetjsonsolution(self.ptr, &mut buf) })?;
+        Ok(unsafe { crate::util::copy_c_str(buf) })
+    }
+
+    /// Export part of the model to a file, for example to isolate a problem while debugging.
+    ///
+    /// A temporary copy of the model is created, and all objects not selected by `subset` are removed from it before it
+    /// is written with [`Model::write`].  This is more expensive than writing the whole model.  See [`SubsetSpec`] for
+    /// the available selections.
+    ///
+    /// # Examples
+    /// ```
+    /// # use grb::prelude::*;
+    /// use grb::SubsetSpec;
+    /// let mut m = Model::new("model")?;
+    /// let x = add_ctsvar!(m)?;
+    /// let y = add_ctsvar!(m)?;
+    /// let c1 = m.add_constr("c1", c!(x + y <= 1))?;
+    /// let c2 = m.add_constr("c2", c!(x - y <= 1))?;
+    /// m.update()?;
+    /// let path = std::env::temp_dir().join("grb_doctest_write_subset.lp");
+    /// m.write_subset(&path, SubsetSpec::Constrs(vec![c2]))?;
+    /// # std::fs::remove_file(&path).ok();
+    /// # Ok::<(), grb::Error>(())
+    /// ```
+    ///
+    /// # Errors
+    /// - [`Error::ModelUpdateNeeded`] if a model update is needed.
+    /// - [`Error::ModelObjectPending`] if some of the selected objects haven't yet been added to the model.
+    /// - [`Error::ModelObjectRemoved`] if some of the selected objects have been removed from the model.
+    /// - [`Error::FromAPI`] if a Gurobi API error occurs.
+    pub fn write_subset(&self, filename: impl AsRef<Path>, subset: SubsetSpec) -> Result<()> {
+        let mut copy = self.try_clone()?;
+        match subset {
+            SubsetSpec::ObjectiveOnly => copy.build_scope(|m| {
+                m.retain_objects::<Constr>(&[])?;
+                m.retain_objects::<QConstr>(&[])?;
+                m.retain_objects::<GenConstr>(&[])?;
+                m.retain_objects::<SOS>(&[])
+            })?,
+            SubsetSpec::Constrs(constrs) => {
+                let keep = constrs
+                    .iter()
+                    .map(|c| self.get_index(c))
+                    .collect::<Result<Vec<_>>>()?;
+                copy.build_scope(|m| {
+                    m.retain_objects::<Constr>(&keep)?;
+                    m.retain_objects::<QConstr>(&[])?;
+                    m.retain_objects::<GenConstr>(&[])?;
+                    m.retain_objects::<SOS>(&[])
+                })?
+            }
+            SubsetSpec::Vars(vars) => {
+                let keep = vars
+                    .iter()
+                    .map(|v| self.get_index(v))
+                    .collect::<Result<Vec<_>>>()?;
+                copy.build_scope(|m| m.retain_objects::<Var>(&keep))?
+            }
+        }
+        copy.write(filename)
+    }
+
+    // remove all objects of type `O`, except those with the given indices
+    fn retain_objects<O: ModelObject>(&mut self, keep: &[i32]) -> Result<()> {
+        let keep: HashSet<i32> = keep.iter().copied().collect();
+        let objects = O::idx_manager(self).objects().to_vec();
+        for (i, o) in objects.into_iter().enumerate() {
+            if !keep.contains(&(i as i32)) {
+                self.remove(o)?;
+            }
+        }
+        Ok(())
+    }
+
     /// Add a decision variable to the model.  This method allows the user to give the entire column (constraint coefficients).
     ///
     /// The [`add_var!`](crate::add_var) macro and its friends are usually easier to use.
+    ///
+    /// Note that a lower bound of `0.0` is *not* the same as no lower bound.  To add a free variable, pass
+    /// [`NEG_INFINITY`](crate::NEG_INFINITY) and [`INFINITY`](crate::INFINITY) as the bounds, or use [`Model::add_free_var`].
+    ///
+    /// # Errors
+    /// - [`Error::AlgebraicError`] if `obj` is NaN or infinite, if `lb` or `ub` is NaN or if any of the
+    ///   column coefficients are NaN. The bounds may be `±INFINITY`.
     #[allow(clippy::too_many_arguments)]
     pub fn add_var(
         &mut self,
@@ -580,10 +1086,21 @@ impl Model {
         ub: f64,
         col_coeff: impl IntoIterator<Item = (Constr, f64)>,
     ) -> Result<Var> {
+        if !obj.is_finite() {
+            return Err(Error::AlgebraicError(format!(
+                "objective coefficient must be finite, got {obj}"
+            )));
+        }
+        if lb.is_nan() || ub.is_nan() {
+            return Err(Error::AlgebraicError(format!(
+                "variable bounds must not be NaN, got [{lb}, {ub}]"
+            )));
+        }
         let name = CString::new(name)?;
         let mut col_coeff = col_coeff.into_iter().peekable();
         let (numnz, _vind, _vval, vind, vval) = if col_coeff.peek().is_some() {
             let (constrs, vals) = self.build_idx_arrays_obj(col_coeff)?;
+            check_no_nan(&vals, "column coefficient")?;
             let c_ptr = constrs.as_ptr();
             let v_ptr = vals.as_ptr();
             (
@@ -612,6 +1129,99 @@ impl Model {
         Ok(self.vars.add_new(self.update_mode_lazy()?))
     }
 
+    /// Add a free decision variable (bounds `[-INFINITY, INFINITY]`) with no constraint coefficients to the model.
+    ///
+    /// # Examples
+    /// ```
+    /// # use grb::prelude::*;
+    /// let mut m = Model::new("model")?;
+    /// let x = m.add_free_var("x", Continuous, 0.0)?;
+    /// # Ok::<(), grb::Error>(())
+    /// ```
+    pub fn add_free_var(&mut self, name: &str, vtype: VarType, obj: f64) -> Result<Var> {
+        self.add_var(
+            name,
+            vtype,
+            obj,
+            crate::NEG_INFINITY,
+            crate::INFINITY,
+            std::iter::empty(),
+        )
+    }
+
+    /// Add multiple decision variables to the model in a single Gurobi API call.  Each variable is given as a
+    /// `(name, vtype, obj, lb, ub)` tuple, and the variables are added without any constraint coefficients.  This is
+    /// much faster than calling [`Model::add_var`] repeatedly when adding many variables.
+    ///
+    /// # Examples
+    /// ```
+    /// # use grb::prelude::*;
+    /// let mut m = Model::new("model")?;
+    /// let vars = m.add_vars_batch((0..10).map(|i| (format!("x{}", i), Continuous, 1.0, 0.0, INFINITY)))?;
+    /// assert_eq!(vars.len(), 10);
+    /// # Ok::<(), grb::Error>(())
+    /// ```
+    ///
+    /// # Errors
+    /// - [`Error::AlgebraicError`] if an objective coefficient is NaN or infinite, or if a bound is NaN.
+    /// - [`Error::NulError`] if a name contains a nul byte.
+    pub fn add_vars_batch(
+        &mut self,
+        specs: impl IntoIterator<Item = (String, VarType, f64, f64, f64)>,
+    ) -> Result<Vec<Var>> {
+        let specs = specs.into_iter();
+        let (n, _) = specs.size_hint();
+        let mut names = Vec::with_capacity(n); // needed to ensure CString lives long enough
+        let mut cnames = Vec::with_capacity(n);
+        let mut vtypes: Vec<ffi::c_char> = Vec::with_capacity(n);
+        let mut objs = Vec::with_capacity(n);
+        let mut lbs = Vec::with_capacity(n);
+        let mut ubs = Vec::with_capacity(n);
+
+        for (name, vtype, obj, lb, ub) in specs {
+            if !obj.is_finite() {
+                return Err(Error::AlgebraicError(format!(
+                    "objective coefficient must be finite, got {obj}"
+                )));
+            }
+            if lb.is_nan() || ub.is_nan() {
+                return Err(Error::AlgebraicError(format!(
+                    "variable bounds must not be NaN, got [{lb}, {ub}]"
+                )));
+            }
+            let name = CString::new(name)?;
+            cnames.push(name.as_ptr());
+            names.push(name);
+            vtypes.push(vtype.into());
+            objs.push(obj);
+            lbs.push(lb);
+            ubs.push(ub);
+        }
+
+        if cnames.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        self.check_apicall(unsafe {
+            ffi::GRBaddvars(
+                self.ptr,
+                cnames.len() as ffi::c_int,
+                0,
+                null(),
+                null(),
+                null(),
+                objs.as_ptr(),
+                lbs.as_ptr(),
+                ubs.as_ptr(),
+                vtypes.as_ptr(),
+                cnames.as_ptr(),
+            )
+        })?;
+
+        let lazy = self.update_mode_lazy()?;
+        Ok((0..cnames.len()).map(|_| self.vars.add_new(lazy)).collect())
+    }
+
     /// Add a Linear constraint to the model.
     ///
     /// The `con` argument is usually created with the [`c!`](crate::c) macro.
@@ -625,10 +1235,20 @@ impl Model {
     /// m.add_constr("c1", c!(x <= 1 - y))?;
     /// # Ok::<(), grb::Error>(())
     /// ```
+    ///
+    /// # Errors
+    /// - [`Error::AlgebraicError`] if the constraint is nonlinear, or if its right-hand side or
+    ///   any of its coefficients are NaN.
     pub fn add_constr(&mut self, name: &str, con: IneqExpr) -> Result<Constr> where {
         let (lhs, sense, rhs) = con.into_normalised_linear()?;
+        if rhs.is_nan() {
+            return Err(Error::AlgebraicError(
+                "constraint right-hand side must not be NaN".to_owned(),
+            ));
+        }
         let constrname = CString::new(name)?;
         let (vinds, cval) = self.get_coeffs_indices_build(&lhs)?;
+        check_no_nan(&cval, "constraint coefficient")?;
         self.check_apicall(unsafe {
             ffi::GRBaddconstr(
                 self.ptr,
@@ -644,10 +1264,11 @@ impl Model {
         Ok(self.constrs.add_new(self.update_mode_lazy()?))
     }
 
-    /// Add multiple linear constraints to the model in a single Gurobi API call.
+    /// Add a linear constraint to the model, given directly as a list of `(variable, coefficient)` terms,
+    /// a sense and a right-hand side.  This is convenient when generating constraints programmatically,
+    /// since it avoids building an [`IneqExpr`].
     ///
-    /// Accepts anything that can be turned into an iterator of `(name, constraint)` pairs
-    /// where `name : AsRef<str>` (eg `&str` or `String`) and `constraint` is a *linear* [`IneqExpr`].
+    /// Unlike [`Model::add_constr`], terms with the same variable are not merged.
     ///
     /// # Examples
     /// ```
@@ -655,12 +1276,61 @@ impl Model {
     /// let mut m = Model::new("model")?;
     /// let x = add_ctsvar!(m)?;
     /// let y = add_ctsvar!(m)?;
+    /// m.add_linear_constr("c1", [(x, 2.0), (y, 3.0)], ConstrSense::Less, 5.0)?;
+    /// # Ok::<(), grb::Error>(())
+    /// ```
+    ///
+    /// # Errors
+    /// - [`Error::AlgebraicError`] if the right-hand side or any of the coefficients are NaN.
+    /// - [`Error::ModelObjectRemoved`] if some variables have been removed from the model.
+    /// - [`Error::ModelObjectMismatch`] if some variables are from a different model.
+    pub fn add_linear_constr(
+        &mut self,
+        name: &str,
+        terms: impl IntoIterator<Item = (Var, f64)>,
+        sense: ConstrSense,
+        rhs: f64,
+    ) -> Result<Constr> {
+        if rhs.is_nan() {
+            return Err(Error::AlgebraicError(
+                "constraint right-hand side must not be NaN".to_owned(),
+            ));
+        }
+        let constrname = CString::new(name)?;
+        let (vinds, cval) = self.build_idx_arrays_obj(terms.into_iter())?;
+        check_no_nan(&cval, "constraint coefficient")?;
+        self.check_apicall(unsafe {
+            ffi::GRBaddconstr(
+                self.ptr,
+                cval.len() as ffi::c_int,
+                vinds.as_ptr(),
+                cval.as_ptr(),
+                sense as ffi::c_char,
+                rhs,
+                constrname.as_ptr(),
+            )
+        })?;
+
+        Ok(self.constrs.add_new(self.update_mode_lazy()?))
+    }
+
+    /// Add multiple linear constraints to the model in a single Gurobi API call.
+    ///
+    /// Accepts anything that can be turned into an iterator of `(name, constraint)` pairs
+    /// where `name : AsRef<str>` (eg `&str` or `String`) and `constraint` is a *linear* [`IneqExpr`].
+    ///
+    /// # Examples
+    /// ```
+    /// # use grb::prelude::*;
+    /// let mut m = Model::new("model")?;
+    /// let x = add_ctsvar!(m)?;
+    /// let y = add_ctsvar!(m)?;
+    ///
+    /// let constraints = vec![
+    ///   (&"c1", c!(x <= 1 - y )),
+    ///   (&"c2", c!(x == 0.5*y )),
+    /// ];
     ///
-    /// let constraints = vec![
-    ///   (&"c1", c!(x <= 1 - y )),
-    ///   (&"c2", c!(x == 0.5*y )),
-    /// ];
-    ///
     /// m.add_constrs(constraints)?;
     ///
     /// // store owned names in Vec to ensure they live long enough
@@ -671,6 +1341,9 @@ impl Model {
     /// # Ok::<(), grb::Error>(())
     /// ```
     ///
+    /// Empty names are cheap: no string is allocated for them, and if every name is empty Gurobi's default
+    /// names are used.
+    ///
     /// # Errors
     /// - [`Error::AlgebraicError`] if a nonlinear constraint is given.
     /// - [`Error::ModelObjectPending`] if some variables haven't yet been added to the model.
@@ -684,7 +1357,8 @@ impl Model {
     {
         let constr_with_name = constr_with_names.into_iter();
         let (nconstr, _) = constr_with_name.size_hint();
-        let mut names = Vec::with_capacity(nconstr); // needed to ensure CString lives long enough
+        let mut names = Vec::new(); // needed to ensure CString lives long enough
+        let empty_name = CString::default();
         let mut cnames = Vec::with_capacity(nconstr);
         let mut rhs = Vec::with_capacity(nconstr);
         let mut cbeg = Vec::with_capacity(nconstr);
@@ -694,9 +1368,14 @@ impl Model {
 
         let mut c_start = 0;
         for (n, c) in constr_with_name {
-            let n = CString::new(n.as_ref())?;
-            cnames.push(n.as_ptr());
-            names.push(n);
+            let n = n.as_ref();
+            if n.is_empty() {
+                cnames.push(empty_name.as_ptr());
+            } else {
+                let n = CString::new(n)?;
+                cnames.push(n.as_ptr());
+                names.push(n);
+            }
             let (lhs, sense, r) = c.into_normalised_linear()?;
             rhs.push(r);
             senses.push(sense as ffi::c_char);
@@ -724,12 +1403,49 @@ impl Model {
                 cval.as_ptr(),
                 senses.as_ptr(),
                 rhs.as_ptr(),
-                cnames.as_ptr(),
+                // all constraints unnamed
+                if names.is_empty() {
+                    null()
+                } else {
+                    cnames.as_ptr()
+                },
             )
         })?;
 
         let lazy = self.update_mode_lazy()?;
-        Ok(vec![self.constrs.add_new(lazy); cnames.len()])
+        Ok((0..cnames.len())
+            .map(|_| self.constrs.add_new(lazy))
+            .collect())
+    }
+
+    /// Add multiple named linear constraints to the model in a single Gurobi API call, returning a map from each
+    /// name to its constraint.  See [`Model::add_constrs`].
+    ///
+    /// If a name is given more than once, all constraints are added, but only the last of them is in the map.
+    ///
+    /// # Examples
+    /// ```
+    /// # use grb::prelude::*;
+    /// let mut m = Model::new("model")?;
+    /// let x = add_ctsvar!(m)?;
+    /// let y = add_ctsvar!(m)?;
+    /// let constrs = m.add_constrs_map(vec![
+    ///   ("c1".to_string(), c!(x <= 1 - y)),
+    ///   ("c2".to_string(), c!(x == 0.5*y)),
+    /// ])?;
+    /// m.set_obj_attr(attr::RHS, &constrs["c1"], 2.0)?;
+    /// # Ok::<(), grb::Error>(())
+    /// ```
+    ///
+    /// # Errors
+    /// See [`Model::add_constrs`].
+    pub fn add_constrs_map(
+        &mut self,
+        constr_with_names: impl IntoIterator<Item = (String, IneqExpr)>,
+    ) -> Result<HashMap<String, Constr>> {
+        let (names, constrs): (Vec<_>, Vec<_>) = constr_with_names.into_iter().unzip();
+        let handles = self.add_constrs(names.iter().zip(constrs))?;
+        Ok(names.into_iter().zip(handles).collect())
     }
 
     /// Add a MIN constraint to the model.
@@ -1047,6 +1763,253 @@ impl Model {
         Ok(self.genconstrs.add_new(self.update_mode_lazy()?))
     }
 
+    /// Add an indicator constraint to the model.  This is equivalent to [`Model::add_genconstr_indicator`], but
+    /// takes an [`IndicatorExpr`], which is created with the [`c!`](crate::c) macro.
+    ///
+    /// # Examples
+    /// ```
+    /// # use grb::prelude::*;
+    /// let mut m = Model::new("model")?;
+    /// let b = add_binvar!(m)?;
+    /// let x = add_ctsvar!(m)?;
+    /// let y = add_ctsvar!(m)?;
+    /// m.add_indicator("c1", c!(b == 1 >> (x <= 1 - y)))?;
+    /// m.add_indicator("c2", c!(b == 0 >> (x + y >= 2)))?;
+    /// # Ok::<(), grb::Error>(())
+    /// ```
+    pub fn add_indicator(&mut self, name: &str, con: IndicatorExpr) -> Result<GenConstr> {
+        let IndicatorExpr {
+            binvar,
+            binval,
+            constr,
+        } = con;
+        self.add_genconstr_indicator(name, binvar, binval, constr)
+    }
+
+    /// Add multiple indicator constraints to the model.  Each item is a tuple of
+    /// `(name, indicator variable, indicator value, implied constraint)`.
+    ///
+    /// The Gurobi C API has no batch call for indicator constraints, so each constraint is still added
+    /// individually.  Before any of them is added, every item is validated, and the constraints are normalised with
+    /// their variable indices resolved into shared buffers.
+    ///
+    /// # Examples
+    /// ```
+    /// # use grb::prelude::*;
+    /// let mut m = Model::new("model")?;
+    /// let x = add_ctsvar!(m, bounds: 0..10)?;
+    /// let b : Vec<_> = (0..3).map(|_| add_binvar!(m)).collect::<grb::Result<_>>()?;
+    /// let constrs = m.add_genconstr_indicators(
+    ///   b.iter().enumerate().map(|(i, &b)| (format!("ind{}", i), b, true, c!(x <= i as f64)))
+    /// )?;
+    /// assert_eq!(constrs.len(), 3);
+    /// # Ok::<(), grb::Error>(())
+    /// ```
+    ///
+    /// # Errors
+    /// - [`Error::AlgebraicError`] if any implied constraint is not linear.
+    /// - [`Error::NulError`] if any name contains a nul byte.
+    ///
+    /// These errors are detected before any constraint is added to the model.
+    pub fn add_genconstr_indicators(
+        &mut self,
+        items: impl IntoIterator<Item = (String, Var, bool, IneqExpr)>,
+    ) -> Result<Vec<GenConstr>> {
+        let items = items.into_iter();
+        let (n, _) = items.size_hint();
+        let mut names = Vec::with_capacity(n);
+        let mut inds = Vec::with_capacity(n);
+        let mut ind_vals = Vec::with_capacity(n);
+        let mut senses = Vec::with_capacity(n);
+        let mut rhs = Vec::with_capacity(n);
+        let mut cbeg = Vec::with_capacity(n + 1);
+        let mut cind = Vec::new();
+        let mut cval = Vec::new();
+
+        cbeg.push(0);
+        for (name, ind, ind_val, con) in items {
+            names.push(CString::new(name)?);
+            inds.push(self.get_index_build(&ind)?);
+            ind_vals.push(ind_val as ffi::c_int);
+            let (lhs, sense, r) = con.into_normalised_linear()?;
+            senses.push(sense as ffi::c_char);
+            rhs.push(r);
+            let (var_coeff, _) = lhs.into_parts();
+            cind.reserve(var_coeff.len());
+            cval.reserve(var_coeff.len());
+            for (var, coeff) in var_coeff {
+                cind.push(self.get_index_build(&var)?);
+                cval.push(coeff);
+            }
+            cbeg.push(cind.len());
+        }
+
+        let lazy = self.update_mode_lazy()?;
+        let mut constrs = Vec::with_capacity(names.len());
+        for (i, name) in names.iter().enumerate() {
+            let (start, end) = (cbeg[i], cbeg[i + 1]);
+            self.check_apicall(unsafe {
+                ffi::GRBaddgenconstrIndicator(
+                    self.ptr,
+                    name.as_ptr(),
+                    inds[i],
+                    ind_vals[i],
+                    (end - start) as ffi::c_int,
+                    cind[start..end].as_ptr(),
+                    cval[start..end].as_ptr(),
+                    senses[i],
+                    rhs[i],
+                )
+            })?;
+            // record each handle as soon as it is added so the model stays consistent if a later call fails
+            constrs.push(self.genconstrs.add_new(lazy));
+        }
+        Ok(constrs)
+    }
+
+    /// Add an indicator constraint which implies a range, $b = v \implies l \le a^T x \le u$, to the model.
+    ///
+    /// Gurobi has no native range indicator constraints, so this adds two indicator constraints with the same
+    /// name and trigger, one for each side of the range.  The constraints are returned in the order
+    /// `(lower, upper)`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use grb::prelude::*;
+    /// let mut m = Model::new("model")?;
+    /// let b = add_binvar!(m)?;
+    /// let x = add_ctsvar!(m)?;
+    /// let y = add_ctsvar!(m)?;
+    /// let (lower, upper) = m.add_genconstr_indicator_range("r1", b, true, c!(x + y in 0..5))?;
+    /// # Ok::<(), grb::Error>(())
+    /// ```
+    ///
+    /// # Errors
+    /// - [`Error::AlgebraicError`] if the expression in the range constraint is not linear.
+    pub fn add_genconstr_indicator_range(
+        &mut self,
+        name: &str,
+        ind: Var,
+        ind_val: bool,
+        con: RangeExpr,
+    ) -> Result<(GenConstr, GenConstr)> {
+        let (expr, lb, ub) = con.into_normalised()?;
+        let lower = IneqExpr {
+            lhs: expr.clone().into(),
+            sense: ConstrSense::Greater,
+            rhs: lb.into(),
+        };
+        let upper = IneqExpr {
+            lhs: expr.into(),
+            sense: ConstrSense::Less,
+            rhs: ub.into(),
+        };
+        let lower = self.add_genconstr_indicator(name, ind, ind_val, lower)?;
+        let upper = self.add_genconstr_indicator(name, ind, ind_val, upper)?;
+        Ok((lower, upper))
+    }
+
+    /// Retrieve the type of a general constraint (the `GenConstrType` attribute).  This can be used to decide which
+    /// method to use to inspect the constraint, such as [`Model::get_genconstr_indicator`].
+    ///
+    /// # Examples
+    /// ```
+    /// # use grb::prelude::*;
+    /// use grb::GenConstrType;
+    /// let mut m = Model::new("model")?;
+    /// let x = add_ctsvar!(m)?;
+    /// let y = add_ctsvar!(m)?;
+    /// let gc = m.add_genconstr_abs("abs", x, y)?;
+    /// m.update()?;
+    /// assert_eq!(m.get_genconstr_type(&gc)?, GenConstrType::Abs);
+    /// # Ok::<(), grb::Error>(())
+    /// ```
+    pub fn get_genconstr_type(&self, gc: &GenConstr) -> Result<GenConstrType> {
+        self.get_obj_attr(attr::GenConstrType, gc)
+    }
+
+    /// Retrieve an indicator constraint, returning the indicator variable, the value which triggers the
+    /// constraint and the implied linear constraint.  The implied constraint has all variables on the left-hand
+    /// side and a constant right-hand side.
+    ///
+    /// # Examples
+    /// ```
+    /// # use grb::prelude::*;
+    /// let mut m = Model::new("model")?;
+    /// let b = add_binvar!(m)?;
+    /// let x = add_ctsvar!(m)?;
+    /// let gc = m.add_genconstr_indicator("ind", b, true, c!(x <= 5))?;
+    /// m.update()?;
+    /// let (ind, ind_val, con) = m.get_genconstr_indicator(&gc)?;
+    /// assert_eq!((ind, ind_val), (b, true));
+    /// # Ok::<(), grb::Error>(())
+    /// ```
+    ///
+    /// # Errors
+    /// - [`Error::ModelUpdateNeeded`] if the model has pending changes.
+    /// - [`Error::ModelObjectRemoved`] if the constraint has been removed from the model.
+    /// - [`Error::InvalidArgument`] if the general constraint is not an indicator constraint.
+    /// - [`Error::NotYetSupported`] if Gurobi reports a constraint sense unknown to this crate.
+    pub fn get_genconstr_indicator(&self, gc: &GenConstr) -> Result<(Var, bool, IneqExpr)> {
+        let vars = self.get_vars()?;
+        if self.genconstrs.model_update_needed() {
+            return Err(Error::ModelUpdateNeeded);
+        }
+        let idx = self.get_index(gc)?;
+        let ty = self.get_genconstr_type(gc)?;
+        if ty != GenConstrType::Indicator {
+            return Err(Error::InvalidArgument(format!(
+                "general constraint is not an indicator constraint ({ty:?})"
+            )));
+        }
+
+        let mut binvar = 0;
+        let mut binval = 0;
+        let mut nvars = 0;
+        let mut sense = 0;
+        let mut rhs = 0.0;
+        self.check_apicall(unsafe {
+            ffi::GRBgetgenconstrIndicator(
+                self.ptr,
+                idx,
+                &mut binvar,
+                &mut binval,
+                &mut nvars,
+                null_mut(),
+                null_mut(),
+                &mut sense,
+                &mut rhs,
+            )
+        })?;
+        let mut ind = vec![0; nvars as usize];
+        let mut val = vec![0.0; nvars as usize];
+        self.check_apicall(unsafe {
+            ffi::GRBgetgenconstrIndicator(
+                self.ptr,
+                idx,
+                &mut binvar,
+                &mut binval,
+                &mut nvars,
+                ind.as_mut_ptr(),
+                val.as_mut_ptr(),
+                &mut sense,
+                &mut rhs,
+            )
+        })?;
+
+        let mut lhs = LinExpr::new();
+        for (i, a) in ind.into_iter().zip(val) {
+            lhs.add_term(a, vars[i as usize]);
+        }
+        let sense = ConstrSense::try_from(sense).map_err(Error::NotYetSupported)?;
+        let con = IneqExpr {
+            lhs: lhs.into(),
+            sense,
+            rhs: rhs.into(),
+        };
+        Ok((vars[binvar as usize], binval != 0, con))
+    }
+
     /// Add a piecewise-linear constraint to the model.
     ///
     /// A piecewise-linear constraint $y = f(x)$ states that
@@ -1131,6 +2094,45 @@ impl Model {
         Ok(self.genconstrs.add_new(self.update_mode_lazy()?))
     }
 
+    /// Add a general nonlinear constraint $y = f(x)$ to the model, where `resvar` is $y$ and `expr` is $f(x)$.
+    /// Requires Gurobi 12 and the `gurobi12` feature.  See the [`nl`](crate::nl) module for how to build expressions.
+    ///
+    /// # Examples
+    /// ```
+    /// # use grb::prelude::*;
+    /// use grb::nl::NlExpr;
+    /// let mut m = Model::new("model")?;
+    /// let x = add_ctsvar!(m)?;
+    /// let y = add_ctsvar!(m)?;
+    /// m.add_genconstr_nl("c1", y, NlExpr::from(x).sin() * 2.0)?;
+    /// # Ok::<(), grb::Error>(())
+    /// ```
+    #[cfg(feature = "gurobi12")]
+    pub fn add_genconstr_nl(
+        &mut self,
+        name: &str,
+        resvar: Var,
+        expr: crate::nl::NlExpr,
+    ) -> Result<GenConstr> {
+        let constrname = CString::new(name)?;
+        let resvar = self.get_index_build(&resvar)?;
+        let (mut opcodes, mut data, mut parents) = expr.flatten(self)?;
+
+        self.check_apicall(unsafe {
+            ffi::GRBaddgenconstrNL(
+                self.ptr,
+                constrname.as_ptr(),
+                resvar,
+                opcodes.len() as ffi::c_int,
+                opcodes.as_mut_ptr(),
+                data.as_mut_ptr(),
+                parents.as_mut_ptr(),
+            )
+        })?;
+
+        Ok(self.genconstrs.add_new(self.update_mode_lazy()?))
+    }
+
     impl_func_constr!(
         "a natural exponent",
         r"$y = \exp(x) or e^x$",
@@ -1244,6 +2246,36 @@ impl Model {
         Ok((var, cons))
     }
 
+    /// Add a range constraint to the model, returning only the constraint.
+    ///
+    /// This is a convenience wrapper around [`Model::add_range`] for when the handle to the range variable is not
+    /// needed. Note that Gurobi still adds the range variable to the model, so it will be present in
+    /// [`Model::get_vars`] after the next update.
+    ///
+    /// # Errors
+    /// See [`Model::add_range`].
+    ///
+    /// # Examples
+    /// ```
+    /// # use grb::prelude::*;
+    /// let mut m = Model::new("model")?;
+    /// let x = add_ctsvar!(m, bounds: ..)?;
+    /// let y = add_ctsvar!(m, bounds: ..)?;
+    /// m.add_range_constr("r", c!(x + y in 2..10))?;
+    ///
+    /// m.set_objective(x + y, Maximize)?;
+    /// m.optimize()?;
+    /// assert_eq!(m.get_attr(attr::ObjVal)?.round(), 10.0);
+    ///
+    /// m.set_objective(x + y, Minimize)?;
+    /// m.optimize()?;
+    /// assert_eq!(m.get_attr(attr::ObjVal)?.round(), 2.0);
+    /// # Ok::<(), grb::Error>(())
+    /// ```
+    pub fn add_range_constr(&mut self, name: &str, expr: RangeExpr) -> Result<Constr> {
+        self.add_range(name, expr).map(|(_, c)| c)
+    }
+
     #[allow(unused_variables)]
     /// Add multiple range constraints to the model in a single API call, analagous to
     /// [`Model::add_constrs`].
@@ -1307,8 +2339,8 @@ impl Model {
 
         let ncons = names.len();
         let lazy = self.update_mode_lazy()?;
-        let vars = vec![self.vars.add_new(lazy); ncons];
-        let cons = vec![self.constrs.add_new(lazy); ncons];
+        let vars = (0..ncons).map(|_| self.vars.add_new(lazy)).collect();
+        let cons = (0..ncons).map(|_| self.constrs.add_new(lazy)).collect();
         Ok((vars, cons))
     }
 
@@ -1349,6 +2381,7 @@ impl Model {
     /// constraint to the model.
     ///
     /// # Errors
+    /// - [`Error::AlgebraicError`] if an SOS1 constraint has no members, or an SOS2 constraint has fewer than two.
     /// - [`Error::ModelObjectPending`] if some variables haven't yet been added to the model.
     /// - [`Error::ModelObjectRemoved`] if some variables have been removed from the model.
     /// - [`Error::ModelObjectMismatch`] if some variables are from a different model.
@@ -1367,6 +2400,7 @@ impl Model {
             ind.push(self.get_index_build(&var)?);
             weight.push(w);
         }
+        check_sos_size(sostype, ind.len())?;
 
         let beg = 0;
         let sostype = sostype as c_int;
@@ -1385,24 +2419,192 @@ impl Model {
         Ok(self.sos.add_new(self.update_mode_lazy()?))
     }
 
-    /// Delete a list of general constraints from an existing model.
+    /// Add a single SOS constraint of type 1 to the model.  Equivalent to calling [`Model::add_sos`] with
+    /// [`SOSType::Ty1`]: at most one of the variables may be non-zero.
+    ///
+    /// # Example
+    /// ```
+    /// # use grb::prelude::*;
+    /// let mut m = Model::new("model")?;
+    /// let x = add_ctsvar!(m)?;
+    /// let y = add_ctsvar!(m)?;
+    /// m.update()?;
+    /// m.add_sos1([(x, 1.0), (y, 2.0)].iter().copied())?;
+    /// # Ok::<(), grb::Error>(())
+    /// ```
     ///
     /// # Errors
-    /// TODO: is this actually the case?
+    /// See [`Model::add_sos`].
+    pub fn add_sos1(
+        &mut self,
+        var_weight_pairs: impl IntoIterator<Item = (Var, f64)>,
+    ) -> Result<SOS> {
+        self.add_sos(var_weight_pairs, SOSType::Ty1)
+    }
+
+    /// Add a single SOS constraint of type 2 to the model.  Equivalent to calling [`Model::add_sos`] with
+    /// [`SOSType::Ty2`]: at most two of the variables may be non-zero, and they must be adjacent in the
+    /// ordering given by the weights.
+    ///
+    /// # Errors
+    /// See [`Model::add_sos`].
+    pub fn add_sos2(
+        &mut self,
+        var_weight_pairs: impl IntoIterator<Item = (Var, f64)>,
+    ) -> Result<SOS> {
+        self.add_sos(var_weight_pairs, SOSType::Ty2)
+    }
+
+    /// Add multiple SOS constraints to the model with a single Gurobi API call.  Each set is given as its
+    /// `(var, weight)` members along with its type.  A handle is returned for each set, in order.
+    ///
+    /// # Example
+    /// ```
+    /// # use grb::prelude::*;
+    /// let mut m = Model::new("model")?;
+    /// let x = add_ctsvar!(m)?;
+    /// let y = add_ctsvar!(m)?;
+    /// let z = add_ctsvar!(m)?;
+    /// m.update()?;
+    /// let sets = vec![
+    ///   (vec![(x, 1.0), (y, 2.0)], SOSType::Ty1),
+    ///   (vec![(x, 1.0), (y, 2.0), (z, 3.0)], SOSType::Ty2),
+    /// ];
+    /// let sos = m.add_sos_batch(sets)?;
+    /// assert_eq!(sos.len(), 2);
+    /// # Ok::<(), grb::Error>(())
+    /// ```
+    ///
+    /// # Errors
+    /// - [`Error::AlgebraicError`] if an SOS1 constraint has no members, or an SOS2 constraint has fewer than two.
     /// - [`Error::ModelObjectPending`] if some variables haven't yet been added to the model.
     /// - [`Error::ModelObjectRemoved`] if some variables have been removed from the model.
     /// - [`Error::ModelObjectMismatch`] if some variables are from a different model.
     /// - [`Error::FromAPI`] if a Gurobi API error occurs.
-    pub fn del_genconstrs(&mut self, constrs: impl IntoIterator<Item = GenConstr>) -> Result<()> {
-        let constr_indices: Vec<_> = constrs
-            .into_iter()
-            .map(|c| self.get_index_build(&c))
-            .collect::<Result<_>>()?;
+    pub fn add_sos_batch(
+        &mut self,
+        sets: impl IntoIterator<Item = (Vec<(Var, f64)>, SOSType)>,
+    ) -> Result<Vec<SOS>> {
+        let mut types = Vec::new();
+        let mut beg = Vec::new();
+        let mut ind = Vec::new();
+        let mut weight = Vec::new();
+
+        for (members, sostype) in sets {
+            check_sos_size(sostype, members.len())?;
+            beg.push(ind.len() as c_int);
+            types.push(sostype as c_int);
+            for (var, w) in members {
+                ind.push(self.get_index_build(&var)?);
+                weight.push(w);
+            }
+        }
+
+        if types.is_empty() {
+            return Ok(Vec::new());
+        }
 
         self.check_apicall(unsafe {
-            ffi::GRBdelgenconstrs(
+            ffi::GRBaddsos(
                 self.ptr,
-                constr_indices.len() as ffi::c_int,
+                types.len() as ffi::c_int,
+                ind.len() as ffi::c_int,
+                types.as_ptr(),
+                beg.as_ptr(),
+                ind.as_ptr(),
+                weight.as_ptr(),
+            )
+        })?;
+
+        let lazy = self.update_mode_lazy()?;
+        Ok((0..types.len()).map(|_| self.sos.add_new(lazy)).collect())
+    }
+
+    /// Retrieve the type of an SOS constraint, along with its members and their weights.
+    ///
+    /// # Example
+    /// ```
+    /// # use grb::prelude::*;
+    /// let mut m = Model::new("model")?;
+    /// let x = add_ctsvar!(m)?;
+    /// let y = add_ctsvar!(m)?;
+    /// m.update()?;
+    /// let s = m.add_sos(vec![(x, 1.0), (y, 2.0)], SOSType::Ty1)?;
+    /// m.update()?;
+    /// let (_, members) = m.get_sos_constr(&s)?;
+    /// assert_eq!(members, [(x, 1.0), (y, 2.0)]);
+    /// # Ok::<(), grb::Error>(())
+    /// ```
+    ///
+    /// # Errors
+    /// - [`Error::ModelUpdateNeeded`] if the model has pending changes.
+    /// - [`Error::ModelObjectRemoved`] if the SOS constraint has been removed from the model.
+    /// - [`Error::ModelObjectMismatch`] if the SOS constraint is from a different model.
+    /// - [`Error::NotYetSupported`] if Gurobi reports an SOS type unknown to this crate.
+    pub fn get_sos_constr(&self, s: &SOS) -> Result<(SOSType, Vec<(Var, f64)>)> {
+        let vars = self.get_vars()?;
+        if self.sos.model_update_needed() {
+            return Err(Error::ModelUpdateNeeded);
+        }
+        let idx = self.get_index(s)?;
+        let mut nummembers = 0;
+        let mut sostype = 0;
+        let mut beg = 0;
+        self.check_apicall(unsafe {
+            ffi::GRBgetsos(
+                self.ptr,
+                &mut nummembers,
+                &mut sostype,
+                &mut beg,
+                null_mut(),
+                null_mut(),
+                idx,
+                1,
+            )
+        })?;
+        let mut ind = vec![0; nummembers as usize];
+        let mut weight = vec![0.0; nummembers as usize];
+        if nummembers > 0 {
+            self.check_apicall(unsafe {
+                ffi::GRBgetsos(
+                    self.ptr,
+                    &mut nummembers,
+                    &mut sostype,
+                    &mut beg,
+                    ind.as_mut_ptr(),
+                    weight.as_mut_ptr(),
+                    idx,
+                    1,
+                )
+            })?;
+        }
+        let sostype = SOSType::try_from(sostype).map_err(Error::NotYetSupported)?;
+        let members = ind
+            .into_iter()
+            .zip(weight)
+            .map(|(i, w)| (vars[i as usize], w))
+            .collect();
+        Ok((sostype, members))
+    }
+
+    /// Delete a list of general constraints from an existing model.
+    ///
+    /// # Errors
+    /// TODO: is this actually the case?
+    /// - [`Error::ModelObjectPending`] if some variables haven't yet been added to the model.
+    /// - [`Error::ModelObjectRemoved`] if some variables have been removed from the model.
+    /// - [`Error::ModelObjectMismatch`] if some variables are from a different model.
+    /// - [`Error::FromAPI`] if a Gurobi API error occurs.
+    pub fn del_genconstrs(&mut self, constrs: impl IntoIterator<Item = GenConstr>) -> Result<()> {
+        let constr_indices: Vec<_> = constrs
+            .into_iter()
+            .map(|c| self.get_index_build(&c))
+            .collect::<Result<_>>()?;
+
+        self.check_apicall(unsafe {
+            ffi::GRBdelgenconstrs(
+                self.ptr,
+                constr_indices.len() as ffi::c_int,
                 constr_indices.as_ptr(),
             )
         })?;
@@ -1410,16 +2612,63 @@ impl Model {
         Ok(())
     }
 
+    /// Start building an objective function from several fragments.  This is useful when the objective
+    /// is assembled in different places, since the (comparatively expensive) call to [`Model::set_objective`]
+    /// is only made once.
+    ///
+    /// # Examples
+    /// ```
+    /// # use grb::prelude::*;
+    /// let mut m = Model::new("model")?;
+    /// let x = add_ctsvar!(m, bounds: 0..1)?;
+    /// let y = add_ctsvar!(m, bounds: 0..1)?;
+    /// let mut obj = m.objective_builder();
+    /// obj += 2 * x;
+    /// obj += y + 1;
+    /// obj.set(Maximize)?;
+    /// # Ok::<(), grb::Error>(())
+    /// ```
+    pub fn objective_builder(&mut self) -> ObjectiveBuilder<'_> {
+        ObjectiveBuilder {
+            model: self,
+            expr: Expr::default(),
+        }
+    }
+
     /// Set the objective function of the model and optimisation direction (min or max).
     /// Because this requires setting a [`Var`] attribute (the `Obj` attribute), this method
     /// always triggers a model update.
     ///
+    /// The direction may be given as a [`ModelSense`], or anything which can be converted into one,
+    /// such as the sign of the objective (`1` to minimise and `-1` to maximise).
+    ///
+    /// # Examples
+    /// ```
+    /// # use grb::prelude::*;
+    /// let mut m = Model::new("model")?;
+    /// let x = add_ctsvar!(m, bounds: 0..1)?;
+    /// m.set_objective(x, Maximize)?;
+    /// m.set_objective(x, -1)?;
+    /// assert_eq!(m.get_attr(attr::ModelSense)?, Maximize);
+    /// m.set_objective(x, ModelSense::minimize_if(true))?;
+    /// assert_eq!(m.get_attr(attr::ModelSense)?, Minimize);
+    /// # Ok::<(), grb::Error>(())
+    /// ```
+    ///
     /// # Errors
+    /// - [`Error::InvalidArgument`] if `sense` cannot be converted to a [`ModelSense`].
     /// - [`Error::ModelObjectPending`] if some variables haven't yet been added to the model.
     /// - [`Error::ModelObjectRemoved`] if some variables have been removed from the model.
     /// - [`Error::ModelObjectMismatch`] if some variables are from a different model.
     /// - [`Error::FromAPI`] if a Gurobi API error occurs.
-    pub fn set_objective(&mut self, expr: impl Into<Expr>, sense: ModelSense) -> Result<()> {
+    pub fn set_objective<S>(&mut self, expr: impl Into<Expr>, sense: S) -> Result<()>
+    where
+        S: TryInto<ModelSense>,
+        S::Error: std::fmt::Display,
+    {
+        let sense = sense
+            .try_into()
+            .map_err(|e| Error::InvalidArgument(e.to_string()))?;
         self.update()?;
         let expr: Expr = expr.into();
         self.del_qpterms()?;
@@ -1446,6 +2695,258 @@ impl Model {
         self.set_attr(attr::ModelSense, sense)
     }
 
+    /// Set the objective function of the model from separate quadratic, linear and constant parts, along with the
+    /// optimisation direction.
+    ///
+    /// The objective is `quad + linear + constant`.  Compared to [`Model::set_objective`], this avoids merging the parts
+    /// into a single expression only to split them apart again, which can be significant for large quadratic objectives.
+    /// Any linear terms or constant in `quad` are also included in the objective.  Like `set_objective`, this method always
+    /// triggers a model update, and accepts the same values for `sense`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use grb::prelude::*;
+    /// use grb::expr::{LinExpr, QuadExpr};
+    /// let mut m = Model::new("model")?;
+    /// let x = add_ctsvar!(m)?;
+    /// let y = add_ctsvar!(m)?;
+    /// let mut quad = QuadExpr::new();
+    /// quad.add_qterm(0.5, x, x).add_qterm(0.5, y, y);
+    /// let mut linear = LinExpr::new();
+    /// linear.add_term(-1.0, x).add_term(2.0, y);
+    /// m.set_objective_parts(quad, linear, 1.0, Minimize)?;
+    /// # Ok::<(), grb::Error>(())
+    /// ```
+    ///
+    /// # Errors
+    /// - [`Error::InvalidArgument`] if `sense` cannot be converted to a [`ModelSense`].
+    /// - [`Error::ModelObjectPending`] if some variables haven't yet been added to the model.
+    /// - [`Error::ModelObjectRemoved`] if some variables have been removed from the model.
+    /// - [`Error::ModelObjectMismatch`] if some variables are from a different model.
+    /// - [`Error::FromAPI`] if a Gurobi API error occurs.
+    pub fn set_objective_parts<S>(
+        &mut self,
+        quad: QuadExpr,
+        linear: LinExpr,
+        constant: f64,
+        sense: S,
+    ) -> Result<()>
+    where
+        S: TryInto<ModelSense>,
+        S::Error: std::fmt::Display,
+    {
+        let sense: ModelSense = sense
+            .try_into()
+            .map_err(|e| Error::InvalidArgument(e.to_string()))?;
+        self.update()?;
+        self.del_qpterms()?;
+        let (qrow, qcol, qval) = self.get_qcoeffs_indices_build(&quad)?;
+        if !qval.is_empty() {
+            self.add_qpterms(&qrow, &qcol, &qval)?;
+        }
+
+        let mut obj = vec![0.0; self.get_vars()?.len()];
+        for (var, coeff) in linear.iter_terms().chain(quad.iter_terms()) {
+            obj[self.get_index(var)? as usize] += coeff;
+        }
+        self.check_apicall(unsafe {
+            ffi::GRBsetdblattrarray(
+                self.ptr,
+                attr::Obj.as_cstr().as_ptr(),
+                0,
+                obj.len() as c_int,
+                obj.as_ptr(),
+            )
+        })?;
+        self.set_attr(
+            attr::ObjCon,
+            constant + linear.get_offset() + quad.get_offset(),
+        )?;
+        self.set_attr(attr::ModelSense, sense)
+    }
+
+    /// Retrieve the objective function of the model and optimisation direction (min or max).
+    ///
+    /// The expression is assembled from the `Obj` variable attributes, the `ObjCon` model attribute and the quadratic
+    /// objective terms.  If the objective has no quadratic terms, an [`Expr::Linear`] is returned,
+    /// otherwise an [`Expr::Quad`] is returned.
+    ///
+    /// # Examples
+    /// ```
+    /// # use grb::prelude::*;
+    /// let mut m = Model::new("model")?;
+    /// let x = add_ctsvar!(m)?;
+    /// m.set_objective(2*x + 1, Maximize)?;
+    /// let (obj, sense) = m.get_objective()?;
+    /// assert!(obj.is_linear());
+    /// assert_eq!(sense, Maximize);
+    /// # Ok::<(), grb::Error>(())
+    /// ```
+    ///
+    /// # Errors
+    /// - [`Error::ModelUpdateNeeded`] if a model update is needed.
+    /// - [`Error::FromAPI`] if a Gurobi API error occurs.
+    pub fn get_objective(&self) -> Result<(Expr, ModelSense)> {
+        let vars = self.get_vars()?;
+        let mut expr = LinExpr::new();
+        for (&var, coeff) in vars
+            .iter()
+            .zip(self.get_obj_attr_batch(attr::Obj, vars.iter().copied())?)
+        {
+            if coeff != 0.0 {
+                expr.add_term(coeff, var);
+            }
+        }
+        expr.add_constant(self.get_attr(attr::ObjCon)?);
+        let sense = self.get_attr(attr::ModelSense)?;
+
+        let numqnz = self.get_attr(attr::NumQNZs)? as usize;
+        if numqnz == 0 {
+            return Ok((expr.into(), sense));
+        }
+
+        let mut qrow = vec![0; numqnz];
+        let mut qcol = vec![0; numqnz];
+        let mut qval = vec![0.0; numqnz];
+        let mut n = 0;
+        self.check_apicall(unsafe {
+            ffi::GRBgetq(
+                self.ptr,
+                &mut n,
+                qrow.as_mut_ptr(),
+                qcol.as_mut_ptr(),
+                qval.as_mut_ptr(),
+            )
+        })?;
+
+        let mut qexpr = Expr::from(expr).into_quadexpr();
+        for k in 0..n as usize {
+            qexpr.add_qterm(qval[k], vars[qrow[k] as usize], vars[qcol[k] as usize]);
+        }
+        Ok((qexpr.into(), sense))
+    }
+
+    /// Set one objective of a multi-objective model.
+    ///
+    /// The `NumObj` attribute is increased to `index + 1` if needed.  The linear coefficients of `expr` are written to
+    /// the `ObjN` attribute of the variables and its constant to `ObjNCon`, after setting the `ObjNumber` parameter to
+    /// `index`.  The `ObjNPriority`, `ObjNWeight` and `ObjNName` attributes of the objective are set to `priority`,
+    /// `weight` and `name` respectively.  Like [`Model::set_objective`], this method always triggers a model update.
+    ///
+    /// # Example
+    /// ```
+    /// # use grb::prelude::*;
+    /// let mut m = Model::new("model")?;
+    /// let x = add_ctsvar!(m, bounds: 0..1)?;
+    /// let y = add_ctsvar!(m, bounds: 0..1)?;
+    /// m.set_objective_n(0, x + y, 2, 1.0, "primary")?;
+    /// m.set_objective_n(1, x - y, 1, 1.0, "secondary")?;
+    /// assert_eq!(m.get_attr(attr::NumObj)?, 2);
+    /// # Ok::<(), grb::Error>(())
+    /// ```
+    ///
+    /// # Errors
+    /// - [`Error::AlgebraicError`] if `expr` is quadratic, since multiple objectives must be linear.
+    /// - [`Error::FromAPI`] if a Gurobi API error occurs.
+    pub fn set_objective_n(
+        &mut self,
+        index: usize,
+        expr: impl Into<Expr>,
+        priority: i32,
+        weight: f64,
+        name: &str,
+    ) -> Result<()> {
+        let expr = expr.into().into_linexpr().map_err(|_| {
+            Error::AlgebraicError("multiple objectives must be linear expressions".to_string())
+        })?;
+        self.update()?;
+        let (coeff_map, obj_cons) = expr.into_parts();
+
+        if (self.get_attr(attr::NumObj)? as usize) <= index {
+            self.set_attr(attr::NumObj, index as i32 + 1)?;
+        }
+        self.set_param(param::ObjNumber, index as i32)?;
+        self.set_obj_attr_batch(
+            attr::ObjN,
+            self.get_vars()?
+                .iter()
+                .map(|v| (*v, coeff_map.get(v).copied().unwrap_or(0.))),
+        )?;
+        self.set_attr(attr::ObjNCon, obj_cons)?;
+        self.set_attr(attr::ObjNPriority, priority)?;
+        self.set_attr(attr::ObjNWeight, weight)?;
+        self.set_attr(attr::ObjNName, name)
+    }
+
+    /// Retrieve one objective of a multi-objective model, as set by [`Model::set_objective_n`].  The `ObjNumber`
+    /// parameter is set to `index`.
+    ///
+    /// # Errors
+    /// - [`Error::ModelUpdateNeeded`] if the model has pending changes.
+    /// - [`Error::InvalidArgument`] if `index` is not less than the `NumObj` attribute.
+    pub fn get_objective_n(&mut self, index: usize) -> Result<LinExpr> {
+        let vars = self.get_vars()?.to_vec();
+        self.select_objective_n(index)?;
+        let mut expr = LinExpr::new();
+        for (&var, coeff) in vars
+            .iter()
+            .zip(self.get_obj_attr_batch(attr::ObjN, vars.iter().copied())?)
+        {
+            if coeff != 0.0 {
+                expr.add_term(coeff, var);
+            }
+        }
+        expr.add_constant(self.get_attr(attr::ObjNCon)?);
+        Ok(expr)
+    }
+
+    /// Set the degradation tolerances of one objective of a multi-objective model (the `ObjNAbsTol` and `ObjNRelTol`
+    /// attributes), which limit how much the objective may be degraded when optimising objectives with lower
+    /// priority.  The `ObjNumber` parameter is set to `index`.  Note that Gurobi ignores these tolerances for
+    /// continuous models.
+    ///
+    /// # Errors
+    /// - [`Error::InvalidArgument`] if `index` is not less than the `NumObj` attribute.
+    pub fn set_objective_n_tolerances(
+        &mut self,
+        index: usize,
+        abs_tol: f64,
+        rel_tol: f64,
+    ) -> Result<()> {
+        self.select_objective_n(index)?;
+        self.set_attr(attr::ObjNAbsTol, abs_tol)?;
+        self.set_attr(attr::ObjNRelTol, rel_tol)
+    }
+
+    // check `index` refers to an existing objective and set the `ObjNumber` parameter to it
+    fn select_objective_n(&mut self, index: usize) -> Result<()> {
+        let num_obj = self.get_attr(attr::NumObj)? as usize;
+        if index >= num_obj {
+            return Err(Error::InvalidArgument(format!(
+                "objective index {index} out of range, model has {num_obj} objectives"
+            )));
+        }
+        self.set_param(param::ObjNumber, index as i32)
+    }
+
+    /// Returns `true` if the objective function has quadratic terms (the `NumQNZs` attribute is nonzero).
+    ///
+    /// Unlike [`Model::get_objective`], this does not read the objective back from the model.
+    pub fn objective_has_quad(&self) -> Result<bool> {
+        Ok(self.get_attr(attr::NumQNZs)? > 0)
+    }
+
+    /// Retrieve the number of variables with a nonzero linear objective coefficient (`Obj` attribute).
+    ///
+    /// # Errors
+    /// - [`Error::ModelUpdateNeeded`] if a model update is needed.
+    /// - [`Error::FromAPI`] if a Gurobi API error occurs.
+    pub fn objective_num_linear_terms(&self) -> Result<usize> {
+        let vars = self.get_vars()?;
+        let obj = self.get_obj_attr_batch(attr::Obj, vars.iter().copied())?;
+        Ok(obj.into_iter().filter(|&c| c != 0.0).count())
+    }
+
     /// Get a constraint by name.  Returns either a constraint if one was found, or `None` if none were found.
     /// If multiple constraints match, the method returns an arbitary one.
     ///
@@ -1510,6 +3011,104 @@ impl Model {
         attr.get(self)
     }
 
+    /// Query the data type and object type of an attribute by name, and whether it can be modified.  This is
+    /// mostly useful for attributes not known to this crate, which can then be queried with the right getter.
+    ///
+    /// # Examples
+    /// ```
+    /// # use grb::prelude::*;
+    /// use grb::attribute::{AttrDataType, AttrObjType};
+    /// let m = Model::new("model")?;
+    /// let info = m.get_attr_info("VarName")?;
+    /// assert_eq!(info.datatype, AttrDataType::String);
+    /// assert_eq!(info.attrtype, AttrObjType::Var);
+    /// assert!(info.settable);
+    /// # Ok::<(), grb::Error>(())
+    /// ```
+    ///
+    /// # Errors
+    /// - [`Error::FromAPI`] if there is no attribute with the given name.
+    /// - [`Error::NulError`] if the name contains a nul byte.
+    pub fn get_attr_info(&self, name: &str) -> Result<AttrInfo> {
+        let name = CString::new(name)?;
+        let (mut datatype, mut attrtype, mut settable) = (-1, -1, -1);
+        self.check_apicall(unsafe {
+            ffi::GRBgetattrinfo(
+                self.ptr,
+                name.as_ptr(),
+                &mut datatype,
+                &mut attrtype,
+                &mut settable,
+            )
+        })?;
+        Ok(AttrInfo {
+            datatype: datatype.try_into()?,
+            attrtype: attrtype.try_into()?,
+            settable: settable != 0,
+        })
+    }
+
+    /// Query an integer model attribute by name.  See [`RawAttr`] for an example.
+    pub fn get_raw_int_attr(&self, attr: &RawAttr) -> Result<i32> {
+        self.get_attr(attr)
+    }
+
+    /// Query an integer model object attribute by name.  See [`RawAttr`] for an example.
+    pub fn get_raw_int_attr_element<O: ModelObject>(&self, attr: &RawAttr, obj: &O) -> Result<i32> {
+        self.get_obj_attr(attr.for_obj(), obj)
+    }
+
+    /// Query an integer model object attribute by name for multiple objects at once.
+    pub fn get_raw_int_attr_batch<O, I>(&self, attr: &RawAttr, objs: I) -> Result<Vec<i32>>
+    where
+        O: ModelObject,
+        I: IntoIterator<Item = O>,
+    {
+        self.get_obj_attr_batch(attr.for_obj(), objs)
+    }
+
+    /// Query a double model attribute by name.  See [`RawAttr`] for an example.
+    pub fn get_raw_dbl_attr(&self, attr: &RawAttr) -> Result<f64> {
+        self.get_attr(attr)
+    }
+
+    /// Query a double model object attribute by name.  See [`RawAttr`] for an example.
+    pub fn get_raw_dbl_attr_element<O: ModelObject>(&self, attr: &RawAttr, obj: &O) -> Result<f64> {
+        self.get_obj_attr(attr.for_obj(), obj)
+    }
+
+    /// Query a double model object attribute by name for multiple objects at once.
+    pub fn get_raw_dbl_attr_batch<O, I>(&self, attr: &RawAttr, objs: I) -> Result<Vec<f64>>
+    where
+        O: ModelObject,
+        I: IntoIterator<Item = O>,
+    {
+        self.get_obj_attr_batch(attr.for_obj(), objs)
+    }
+
+    /// Query a string model attribute by name.  See [`RawAttr`] for an example.
+    pub fn get_raw_str_attr(&self, attr: &RawAttr) -> Result<String> {
+        self.get_attr(attr)
+    }
+
+    /// Query a string model object attribute by name.  See [`RawAttr`] for an example.
+    pub fn get_raw_str_attr_element<O: ModelObject>(
+        &self,
+        attr: &RawAttr,
+        obj: &O,
+    ) -> Result<String> {
+        self.get_obj_attr(attr.for_obj(), obj)
+    }
+
+    /// Query a string model object attribute by name for multiple objects at once.
+    pub fn get_raw_str_attr_batch<O, I>(&self, attr: &RawAttr, objs: I) -> Result<Vec<String>>
+    where
+        O: ModelObject,
+        I: IntoIterator<Item = O>,
+    {
+        self.get_obj_attr_batch(attr.for_obj(), objs)
+    }
+
     /// Query a model object attribute (Constr, Var, etc).  Available attributes can be found
     /// in the [`attr`] module, which is imported in the [prelude](crate::prelude).
     pub fn get_obj_attr<A, O, V>(&self, attr: A, obj: &O) -> Result<V>
@@ -1589,36 +3188,112 @@ impl Model {
         )
     }
 
-    /// Set a model parameter.  Parameters (objects with the `Param` trait) can be found in the [`param`] module.
+    /// Query the name of a model object.  This is equivalent to calling [`Model::get_obj_attr`] with the name
+    /// attribute of the object type (eg `attr::VarName` for [`Var`], `attr::ConstrName` for [`Constr`]).
     ///
     /// # Example
     /// ```
     /// # use grb::prelude::*;
+    /// use grb::NamedModelObject;
+    ///
+    /// fn names<O: NamedModelObject>(m: &Model, objs: &[O]) -> grb::Result<Vec<String>> {
+    ///     objs.iter().map(|o| m.get_name(o)).collect()
+    /// }
+    ///
     /// let mut model = Model::new("")?;
-    /// model.set_param(param::OutputFlag, 0)?;
+    /// let x = add_ctsvar!(model, name: "x")?;
+    /// let c = model.add_constr("c", c!(x <= 1))?;
+    /// model.update()?;
+    /// assert_eq!(names(&model, &[x])?, ["x"]);
+    /// assert_eq!(names(&model, &[c])?, ["c"]);
     /// # Ok::<(), grb::Error>(())
     /// ```
-    pub fn set_param<P: ParamSet<V>, V>(&mut self, param: P, value: V) -> Result<()> {
-        self.get_env_mut().set(param, value)
+    pub fn get_name<O: NamedModelObject>(&self, obj: &O) -> Result<String> {
+        self.get_obj_attr(O::NAME_ATTR, obj)
     }
 
-    /// Query a model parameter.  Parameters (objects with the `Param` trait) can be found in the [`param`] module.
+    /// Set the name of a model object.  See [`Model::get_name`].
+    pub fn set_name<O: NamedModelObject>(&self, obj: &O, name: &str) -> Result<()> {
+        self.set_obj_attr(O::NAME_ATTR, obj, name)
+    }
+
+    /// Rename all variables in the model, by applying `f` to each existing name.  The names are read and written
+    /// in a single batch each, which is much faster than calling [`Model::set_name`] for each variable.
     ///
     /// # Example
     /// ```
     /// # use grb::prelude::*;
     /// let mut model = Model::new("")?;
-    /// assert_eq!(model.get_param(param::LazyConstraints)?, 0);
+    /// let x = add_ctsvar!(model, name: "x")?;
+    /// model.update()?;
+    /// model.rename_vars(|name| format!("a_{}", name))?;
+    /// model.update()?;
+    /// assert_eq!(model.get_name(&x)?, "a_x");
     /// # Ok::<(), grb::Error>(())
     /// ```
-    pub fn get_param<P: ParamGet<V>, V>(&self, param: P) -> Result<V> {
-        self.get_env().get(param)
+    ///
+    /// # Errors
+    /// Returns [`Error::ModelUpdateNeeded`] if the model has pending changes.
+    pub fn rename_vars(&mut self, f: impl FnMut(&str) -> String) -> Result<()> {
+        let vars = self.get_vars()?.to_vec();
+        self.rename_objects(vars, f)
     }
 
-    /// Modify the model to create a feasibility relaxation.
+    /// Rename all linear constraints in the model, by applying `f` to each existing name. See [`Model::rename_vars`].
     ///
-    /// Given a `Model` whose objective function is $f(x)$, the feasibility relaxation seeks to minimise
-    /// $$
+    /// # Errors
+    /// Returns [`Error::ModelUpdateNeeded`] if the model has pending changes.
+    pub fn rename_constrs(&mut self, f: impl FnMut(&str) -> String) -> Result<()> {
+        let constrs = self.get_constrs()?.to_vec();
+        self.rename_objects(constrs, f)
+    }
+
+    fn rename_objects<O: NamedModelObject>(
+        &mut self,
+        objs: Vec<O>,
+        mut f: impl FnMut(&str) -> String,
+    ) -> Result<()> {
+        let names: Vec<String> = self
+            .get_obj_attr_batch(O::NAME_ATTR, objs.iter().copied())?
+            .iter()
+            .map(|name| f(name))
+            .collect();
+        self.set_obj_attr_batch(
+            O::NAME_ATTR,
+            objs.into_iter().zip(names.iter().map(String::as_str)),
+        )
+    }
+
+    /// Set a model parameter.  Parameters (objects with the `Param` trait) can be found in the [`param`] module.
+    ///
+    /// # Example
+    /// ```
+    /// # use grb::prelude::*;
+    /// let mut model = Model::new("")?;
+    /// model.set_param(param::OutputFlag, 0)?;
+    /// # Ok::<(), grb::Error>(())
+    /// ```
+    pub fn set_param<P: ParamSet<V>, V>(&mut self, param: P, value: V) -> Result<()> {
+        self.get_env_mut().set(param, value)
+    }
+
+    /// Query a model parameter.  Parameters (objects with the `Param` trait) can be found in the [`param`] module.
+    ///
+    /// # Example
+    /// ```
+    /// # use grb::prelude::*;
+    /// let mut model = Model::new("")?;
+    /// assert_eq!(model.get_param(param::LazyConstraints)?, 0);
+    /// # Ok::<(), grb::Error>(())
+    /// ```
+    pub fn get_param<P: ParamGet<V>, V>(&self, param: P) -> Result<V> {
+        self.get_env().get(param)
+    }
+
+    /// Modify the model to create a feasibility relaxation.
+    ///
+    /// Given a `Model` whose objective function is $f(x)$, the feasibility relaxation seeks to minimise
+    /// $$
     ///   \text{min}\quad f(x) + \sum_{j} w_j \cdot p(s_j)
     /// $$
     /// where $s\_j > 0$ is the slack variable of $j$ -th constraint or bound, $w_j$ is the $j$-th weight
@@ -1734,6 +3409,63 @@ impl Model {
         Ok((feasobj, new_vars, new_cons, new_qcons))
     }
 
+    /// Modify the model to create a feasibility relaxation, pairing each penalized constraint with its slack
+    /// variable(s).
+    ///
+    /// The arguments are the same as [`Model::feas_relax`].  The pairs are returned in the order of `constr_pen`.
+    /// An inequality constraint has a single slack variable, while an equality constraint has two: one for
+    /// each direction of violation.
+    ///
+    /// # Example
+    /// ```
+    /// # use grb::prelude::*;
+    /// let mut m = Model::new("model")?;
+    /// let x = add_ctsvar!(m, bounds: 0..1)?;
+    /// let c1 = m.add_constr("c1", c!(x >= 2))?;
+    /// m.add_constr("c2", c!(x <= 1))?;
+    /// let relaxed = m.feas_relax_mapped(
+    ///     RelaxType::Linear,
+    ///     false,
+    ///     std::iter::empty(),
+    ///     std::iter::empty(),
+    ///     vec![(c1, 1.0)],
+    /// )?;
+    /// assert_eq!(relaxed.slacks.len(), 1);
+    /// assert_eq!(relaxed.slacks[0].0, c1);
+    /// # Ok::<(), grb::Error>(())
+    /// ```
+    pub fn feas_relax_mapped(
+        &mut self,
+        ty: RelaxType,
+        minrelax: bool,
+        lb_pen: impl IntoIterator<Item = (Var, f64)>,
+        ub_pen: impl IntoIterator<Item = (Var, f64)>,
+        constr_pen: impl IntoIterator<Item = (Constr, f64)>,
+    ) -> Result<FeasRelaxSlacks> {
+        let constr_pen: Vec<_> = constr_pen.into_iter().collect();
+        let constrs: Vec<_> = constr_pen.iter().map(|&(c, _)| c).collect();
+        let (feasobj, new_vars, _, _) =
+            self.feas_relax(ty, minrelax, lb_pen, ub_pen, constr_pen)?;
+        let n_old_vars = self.get_attr(attr::NumVars)? as usize - new_vars.len();
+
+        let mut pairs = Vec::with_capacity(constrs.len());
+        for c in constrs {
+            let (inds, _) = self.get_constr_coeffs(&c)?;
+            for i in inds {
+                if let Some(&v) = (i as usize)
+                    .checked_sub(n_old_vars)
+                    .and_then(|i| new_vars.get(i))
+                {
+                    pairs.push((c, v));
+                }
+            }
+        }
+        Ok(FeasRelaxSlacks {
+            feas_obj: feasobj,
+            slacks: pairs,
+        })
+    }
+
     /// Capture a single scenario from a multi-scenario model. Use the `ScenarioNumber` parameter to indicate which
     /// scenario to capture. See the
     /// [manual](https://www.gurobi.com/documentation/9.5/refman/multiple_scenarios.html#sec:MultipleScenarios)
@@ -1747,6 +3479,27 @@ impl Model {
         Model::from_raw(self.get_env(), model_ptr)
     }
 
+    /// Capture every scenario of a multi-scenario model, returning one model per scenario in order of the
+    /// scenario number.  Each model has its own copy of the environment, and is independent of `self`.
+    ///
+    /// This calls [`Model::single_scenario_model`] once for each scenario, changing the `ScenarioNumber`
+    /// parameter as it goes.  The original value of the parameter is restored before returning, including when
+    /// capturing a scenario fails.
+    pub fn scenario_models(&mut self) -> Result<Vec<Model>> {
+        let n = self.get_attr(attr::NumScenarios)?;
+        let old_scenario = self.get_param(param::ScenarioNumber)?;
+        let models = (0..n)
+            .map(|i| {
+                self.set_param(param::ScenarioNumber, i)?;
+                self.single_scenario_model()
+            })
+            .collect::<Result<Vec<_>>>();
+        let restored = self.set_param(param::ScenarioNumber, old_scenario);
+        let models = models?;
+        restored?;
+        Ok(models)
+    }
+
     /// Set a piecewise-linear objective function for the variable.
     ///
     /// Given a sequence of points $(x_1, y_1), \dots, (x_n, y_n)$, the piecewise-linear objective function
@@ -1791,446 +3544,3218 @@ impl Model {
         })
     }
 
-    /// Retrieve the status of the model.
-    pub fn status(&self) -> Result<Status> {
-        self.get_attr(attr::Status)
-    }
-
-    impl_object_list_getter!(get_vars, Var, vars, "variables");
-
-    impl_object_list_getter!(get_constrs, Constr, constrs, "constraints");
-
-    impl_object_list_getter!(get_genconstrs, GenConstr, genconstrs, "general constraints");
-
-    impl_object_list_getter!(get_qconstrs, QConstr, qconstrs, "quadratic constraints");
-
-    impl_object_list_getter!(get_sos, SOS, sos, "SOS constraints");
-
-    /// Remove a variable or constraint from the model.
-    pub fn remove<O: ModelObject>(&mut self, item: O) -> Result<()> {
-        let lazy = self.update_mode_lazy()?;
-        let im = O::idx_manager_mut(self);
-        let idx = im.get_index(&item)?;
-        im.remove(item, lazy)?;
-        self.check_apicall(unsafe { O::gurobi_remove(self.ptr, &[idx]) })
-    }
-
-    /// Retrieve a single constant matrix coefficient of the model.
-    pub fn get_coeff(&self, var: &Var, constr: &Constr) -> Result<f64> {
-        let mut value = 0.0;
+    /// Retrieve the piecewise-linear objective function of a variable as the list of points $(x_i, y_i)$
+    /// passed to [`Model::set_pwl_obj`].  If the variable has no piecewise-linear objective, the list is empty.
+    ///
+    /// # Examples
+    /// ```
+    /// # use grb::prelude::*;
+    /// let mut m = Model::new("model")?;
+    /// let x = add_ctsvar!(m, bounds: 0..10)?;
+    /// m.set_pwl_obj(&x, vec![(0.0, 1.0), (5.0, 0.0), (10.0, 3.0)])?;
+    /// m.update()?;
+    /// assert_eq!(m.get_pwl_obj(&x)?, vec![(0.0, 1.0), (5.0, 0.0), (10.0, 3.0)]);
+    /// # Ok::<(), grb::Error>(())
+    /// ```
+    pub fn get_pwl_obj(&self, var: &Var) -> Result<Vec<(f64, f64)>> {
+        let idx = self.get_index(var)?;
+        let mut npoints = 0;
         self.check_apicall(unsafe {
-            ffi::GRBgetcoeff(
-                self.ptr,
-                self.get_index_build(constr)?,
-                self.get_index_build(var)?,
-                &mut value,
-            )
+            ffi::GRBgetpwlobj(self.ptr, idx, &mut npoints, null_mut(), null_mut())
         })?;
-        Ok(value)
-    }
 
-    /// Change a single constant matrix coefficient of the model.
-    pub fn set_coeff(&mut self, var: &Var, constr: &Constr, value: f64) -> Result<()> {
+        let mut xvals = vec![0.0; npoints as usize];
+        let mut yvals = vec![0.0; npoints as usize];
         self.check_apicall(unsafe {
-            ffi::GRBchgcoeffs(
+            ffi::GRBgetpwlobj(
                 self.ptr,
-                1,
-                &self.get_index_build(constr)?,
-                &self.get_index_build(var)?,
-                &value,
-            )
-        })
-    }
-
-    /// Change a set of constant matrix coefficients of the model.
-    pub fn set_coeffs(
-        &mut self,
-        coeffs: impl IntoIterator<Item = (Var, Constr, f64)>,
-    ) -> Result<()> {
-        let (vind, cind, val) = self.build_idx_arrays_obj_obj(coeffs.into_iter())?;
-        self.check_apicall(unsafe {
-            ffi::GRBchgcoeffs(
-                self.as_mut_ptr(),
-                vind.len() as ffi::c_int,
-                cind.as_ptr(),
-                vind.as_ptr(),
-                val.as_ptr(),
+                idx,
+                &mut npoints,
+                xvals.as_mut_ptr(),
+                yvals.as_mut_ptr(),
             )
-        })
+        })?;
+        Ok(xvals.into_iter().zip(yvals).collect())
     }
 
-    // add quadratic terms of objective function.
-    fn add_qpterms(&mut self, qrow: &[i32], qcol: &[i32], qval: &[f64]) -> Result<()> {
+    /// Set the MIP start (the `Start` attribute) of multiple variables.
+    ///
+    /// When the variables form a contiguous block of indices in ascending order (for example, when a start is
+    /// given for every variable in the order of [`Model::get_vars`]), the values are set with a single call to
+    /// the contiguous array API, which is considerably faster on large models.  Otherwise, this is equivalent
+    /// to calling [`Model::set_obj_attr_batch`] with [`attr::Start`].
+    ///
+    /// # Examples
+    /// ```
+    /// # use grb::prelude::*;
+    /// let mut m = Model::new("model")?;
+    /// let x = add_intvar!(m, bounds: 0..10)?;
+    /// let y = add_intvar!(m, bounds: 0..10)?;
+    /// m.update()?;
+    /// m.set_start([(x, 1.0), (y, 2.0)])?;
+    /// m.update()?;
+    /// assert_eq!(m.get_obj_attr_batch(attr::Start, [x, y])?, [1.0, 2.0]);
+    /// # Ok::<(), grb::Error>(())
+    /// ```
+    ///
+    /// # Errors
+    /// - [`Error::ModelObjectPending`] if some variables haven't yet been added to the model.
+    /// - [`Error::ModelObjectRemoved`] if some variables have been removed from the model.
+    /// - [`Error::ModelObjectMismatch`] if some variables are from a different model.
+    /// - [`Error::FromAPI`] if a Gurobi API error occurs.
+    pub fn set_start(&mut self, starts: impl IntoIterator<Item = (Var, f64)>) -> Result<()> {
+        let (inds, vals) = self.build_idx_arrays_obj(starts.into_iter())?;
+        let attrname = attr::Start.as_cstr().as_ptr();
         self.check_apicall(unsafe {
-            ffi::GRBaddqpterms(
-                self.ptr,
-                qrow.len() as ffi::c_int,
-                qrow.as_ptr(),
-                qcol.as_ptr(),
-                qval.as_ptr(),
-            )
+            match crate::util::contiguous_start(&inds) {
+                Some(first) => ffi::GRBsetdblattrarray(
+                    self.ptr,
+                    attrname,
+                    first,
+                    vals.len() as c_int,
+                    vals.as_ptr(),
+                ),
+                None => ffi::GRBsetdblattrlist(
+                    self.ptr,
+                    attrname,
+                    inds.len() as c_int,
+                    inds.as_ptr(),
+                    vals.as_ptr(),
+                ),
+            }
         })
     }
 
-    // remove quadratic terms of objective function.
-    fn del_qpterms(&mut self) -> Result<()> {
-        self.check_apicall(unsafe { ffi::GRBdelq(self.ptr) })
+    /// Set the `Start` attribute of a single variable, like [`Model::set_start`], but without requiring the variable to
+    /// have been added to the model yet.  If `var` is still pending (see [`Error::ModelObjectPending`]), the value is
+    /// queued and set during the next call to [`Model::update`], after the variable has been added.  This is what
+    /// the `start` argument of [`add_var!`](crate::add_var) uses.
+    ///
+    /// # Examples
+    /// ```
+    /// # use grb::prelude::*;
+    /// let mut env = Env::new("")?;
+    /// env.set(param::UpdateMode, 0)?;
+    /// let mut m = Model::with_env("model", &env)?;
+    /// let x = add_intvar!(m, bounds: 0..10)?;
+    /// m.set_start_deferred(x, 3.0)?;
+    /// m.update()?;
+    /// assert_eq!(m.get_obj_attr(attr::Start, &x)?, 3.0);
+    /// # Ok::<(), grb::Error>(())
+    /// ```
+    ///
+    /// # Errors
+    /// - [`Error::ModelObjectRemoved`] if `var` has been removed from the model.
+    /// - [`Error::ModelObjectMismatch`] if `var` is from a different model.
+    /// - [`Error::FromAPI`] if a Gurobi API error occurs.
+    pub fn set_start_deferred(&mut self, var: Var, start: f64) -> Result<()> {
+        match self.get_index_build(&var) {
+            Err(Error::ModelObjectPending) => {
+                self.pending_starts.push((var, start));
+                Ok(())
+            }
+            Err(e) => Err(e),
+            Ok(_) => self.set_start(std::iter::once((var, start))),
+        }
     }
 
-    pub(crate) fn check_apicall(&self, error: ffi::c_int) -> Result<()> {
-        if error != 0 {
-            return Err(self.env.error_from_api(error));
+    /// Set variable hints for the MIP search.  Each item is a tuple `(var, value, priority)`, which sets the `VarHintVal`
+    /// attribute of `var` to `value` and, if `priority` is `Some`, the `VarHintPri` attribute to the priority.
+    /// Unlike a MIP start, hints only guide the search and need not form a feasible solution.
+    ///
+    /// # Examples
+    /// ```
+    /// # use grb::prelude::*;
+    /// let mut m = Model::new("model")?;
+    /// let x = add_binvar!(m)?;
+    /// let y = add_binvar!(m)?;
+    /// m.update()?;
+    /// m.set_var_hints([(x, 1.0, Some(10)), (y, 0.0, None)])?;
+    /// # Ok::<(), grb::Error>(())
+    /// ```
+    ///
+    /// # Errors
+    /// - [`Error::ModelObjectPending`] if some variables haven't yet been added to the model.
+    /// - [`Error::ModelObjectRemoved`] if some variables have been removed from the model.
+    /// - [`Error::ModelObjectMismatch`] if some variables are from a different model.
+    /// - [`Error::FromAPI`] if a Gurobi API error occurs.
+    pub fn set_var_hints(
+        &mut self,
+        hints: impl IntoIterator<Item = (Var, f64, Option<i32>)>,
+    ) -> Result<()> {
+        let mut vals = Vec::new();
+        let mut priorities = Vec::new();
+        for (var, val, priority) in hints {
+            vals.push((var, val));
+            if let Some(p) = priority {
+                priorities.push((var, p));
+            }
+        }
+        self.set_obj_attr_batch(attr::VarHintVal, vals)?;
+        if !priorities.is_empty() {
+            self.set_obj_attr_batch(attr::VarHintPri, priorities)?;
         }
         Ok(())
     }
-}
 
-impl Drop for Model {
-    fn drop(&mut self) {
-        // Note: This method runs *before* the `drop()` method on the env inside the model
-        // so we free the GRBModel before the GRBEnv, as per the Gurobi docs.
-        unsafe { ffi::GRBfreemodel(self.ptr) };
+    /// The number of MIP starts in the model (the `NumStart` attribute).
+    pub fn num_start_vectors(&self) -> Result<i32> {
+        self.get_attr(attr::NumStart)
     }
-}
 
-/// A handle to an [`AsyncModel`] which is currently solving.
-pub struct AsyncHandle(Model);
+    /// Add a new MIP start to the model, returning its index.  Variables which are not in `assignments` have no
+    /// start value in the new MIP start.
+    ///
+    /// This increments the `NumStart` attribute, updates the model, and sets the `StartNumber` parameter to the
+    /// new index before setting the `Start` attribute with [`Model::set_start`].  The `StartNumber` parameter is left
+    /// pointing to the new MIP start.  Note that Gurobi counts a start set with [`Model::set_start`] on a model
+    /// without any MIP starts as the first MIP start.
+    ///
+    /// # Examples
+    /// ```
+    /// # use grb::prelude::*;
+    /// let mut m = Model::new("model")?;
+    /// let x = add_intvar!(m, bounds: 0..10)?;
+    /// let y = add_intvar!(m, bounds: 0..10)?;
+    /// assert_eq!(m.add_mip_start([(x, 1.0), (y, 2.0)])?, 0);
+    /// assert_eq!(m.add_mip_start([(x, 3.0)])?, 1);
+    /// assert_eq!(m.num_start_vectors()?, 2);
+    /// # Ok::<(), grb::Error>(())
+    /// ```
+    ///
+    /// # Errors
+    /// See [`Model::set_start`].
+    pub fn add_mip_start(
+        &mut self,
+        assignments: impl IntoIterator<Item = (Var, f64)>,
+    ) -> Result<i32> {
+        let idx = self.num_start_vectors()?;
+        self.set_attr(attr::NumStart, idx + 1)?;
+        self.update()?;
+        self.set_param(param::StartNumber, idx)?;
+        self.set_start(assignments)?;
+        Ok(idx)
+    }
 
-impl AsyncHandle {
-    /// Retrieve current the `attr::Status` of the model.
+    /// Retrieve the status of the model.
     pub fn status(&self) -> Result<Status> {
-        self.0.status()
+        self.get_attr(attr::Status)
     }
 
-    /// Retrieve the current `attr::ObjVal` of the model.
-    pub fn obj_val(&self) -> Result<f64> {
-        self.0.get_attr(attr::ObjVal)
+    /// Returns `true` if the model has discrete elements, such as integer variables, SOS
+    /// constraints or general constraints (the `IsMIP` attribute).
+    pub fn is_mip(&self) -> Result<bool> {
+        Ok(self.get_attr(attr::IsMIP)? != 0)
     }
 
-    /// Retrieve the current  `attr::ObjBound` of the model.
-    pub fn obj_bnd(&self) -> Result<f64> {
-        self.0.get_attr(attr::ObjBound)
+    /// Returns `true` if the model has a quadratic objective function (the `IsQP` attribute).
+    pub fn is_qp(&self) -> Result<bool> {
+        Ok(self.get_attr(attr::IsQP)? != 0)
     }
 
-    /// Retrieve the current `attr::IterCount` of the model.
-    pub fn iter_cnt(&self) -> Result<f64> {
-        self.0.get_attr(attr::IterCount)
+    /// Returns `true` if the model has quadratic constraints (the `IsQCP` attribute).
+    pub fn is_qcp(&self) -> Result<bool> {
+        Ok(self.get_attr(attr::IsQCP)? != 0)
     }
 
-    /// Retrieve the current `attr::BarIterCount` of the model.
-    pub fn bar_iter_cnt(&self) -> Result<i32> {
-        self.0.get_attr(attr::BarIterCount)
+    /// Retrieve the wall-clock time in seconds taken by the most recent optimisation (the `Runtime` attribute).
+    pub fn runtime(&self) -> Result<f64> {
+        self.get_attr(attr::Runtime)
     }
 
-    /// Retrieve the current `attr::NodeCount` of the model.
-    pub fn node_cnt(&self) -> Result<f64> {
-        self.0.get_attr(attr::NodeCount)
+    /// Retrieve the deterministic work spent by the most recent optimisation, in work units (the `Work` attribute).
+    /// Unlike [`Model::runtime`], this does not depend on the load of the machine.
+    pub fn work(&self) -> Result<f64> {
+        self.get_attr(attr::Work)
     }
 
-    /// Wait for optimisation to finish.
+    /// Retrieve the indices of the basic variables of the current simplex basis (the basis head).  The returned
+    /// vector has one entry per linear constraint.  An entry `j` less than the number of variables refers to the
+    /// `j`-th variable (in the order of [`Model::get_vars`]), while an entry `j` of at least the number of variables
+    /// refers to the slack variable of the constraint with index `j - num_vars`.
     ///
     /// # Errors
-    /// An [`Error::FromAPI`] may occur during optimisation, in which case it is stored in the `Result`.
-    pub fn join(self) -> (AsyncModel, Result<()>) {
-        let errors = self.0.check_apicall(unsafe { ffi::GRBsync(self.0.ptr) });
-        (AsyncModel(self.0), errors)
+    /// Returns [`Error::DataNotAvailable`] if the model is a MIP or has not been solved to optimality, since no basis
+    /// is available.
+    pub fn get_basis_head(&self) -> Result<Vec<i32>> {
+        if self.is_mip()? || self.status()? != Status::Optimal {
+            return Err(Error::DataNotAvailable(
+                "basis head requires an LP solved to optimality".to_owned(),
+            ));
+        }
+        let mut bhead = vec![0; self.num_constrs()?];
+        self.check_apicall(unsafe { ffi::GRBgetBasisHead(self.ptr, bhead.as_mut_ptr()) })?;
+        Ok(bhead)
     }
 
-    /// Send a request to Gurobi to terminate optimization.  Optimization may not finish immediately.
+    /// Compute row `i` of the simplex tableau, `B⁻¹A` (where `A` includes the slack columns).  The row is indexed
+    /// by both variables and slacks, like the basis head (see [`Model::get_basis_head`]): an index `j` less than
+    /// the number of variables refers to the `j`-th variable, otherwise to the slack of constraint `j - num_vars`.
     ///
-    /// # Example
-    /// ```
-    /// # use grb::prelude::*;
-    /// use grb::AsyncModel;
-    /// let e = Env::new("")?;
-    /// let m = Model::with_env("async", e)?;
-    /// # /*
-    ///   ...
-    /// # */
-    /// let m = AsyncModel::new(m);
-    /// // discard `AsyncModel` on failure and panic
-    /// let handle = m.optimize().map_err(|(_, e)| e).unwrap();
-    /// # /*
-    ///   ...
-    /// # */
-    /// handle.terminate();
-    /// # let (m, errors) = handle.join();
-    /// # errors?;
-    /// # Ok::<(), grb::Error>(())
-    /// ```
-    pub fn terminate(&self) {
-        self.0.terminate();
+    /// The model must be an LP which has been solved to optimality.  Note that the tableau refers to the original
+    /// model, so if presolve changed the model, the basis may not be available; set the `Presolve` parameter to `0`
+    /// before solving to be safe.
+    ///
+    /// # Errors
+    /// - [`Error::DataNotAvailable`] if no basis is available.
+    /// - [`Error::FromAPI`] if `i` is out of range.
+    pub fn tableau_row(&self, i: i32) -> Result<SparseVec> {
+        let n = self.num_vars()? + self.num_constrs()?;
+        self.get_svec(n, |svec| unsafe { ffi::GRBBinvRowi(self.ptr, i, svec) })
     }
-}
 
-/// A wrapper around [`Model`] that supports async optimisation in the background.
-///
-///  From the Gurobi [manual](https://www.gurobi.com/documentation/9.1/refman/c_optimizeasync.html), regarding solving models asynchronously:
-///
-/// *"[modifying or performing non-permitted] calls on the running model, **or on any other models that were built within the same Gurobi environment**,
-///  will fail with error code `OPTIMIZATION_IN_PROGRESS`."*
-///
-/// For this reason, creating an `AsyncModel` requires a [`Model`] whose [`Env`] wasn't previously been used to construct other models.
-///
-/// `Model` implements `From<AsyncModel>`, so you can recover the `Model` using `.into()` (see examples).
-pub struct AsyncModel(Model);
+    /// Compute column `j` of the simplex tableau, `B⁻¹Aⱼ`, where column `j` refers to a variable or slack in the same
+    /// way as [`Model::tableau_row`].  The entries are indexed by the rows of the tableau (the positions in the
+    /// basis head).  The same restrictions as [`Model::tableau_row`] apply.
+    ///
+    /// # Errors
+    /// - [`Error::DataNotAvailable`] if no basis is available.
+    /// - [`Error::FromAPI`] if `j` is out of range.
+    pub fn tableau_col(&self, j: i32) -> Result<SparseVec> {
+        let n = self.num_constrs()?;
+        self.get_svec(n, |svec| unsafe { ffi::GRBBinvColj(self.ptr, j, svec) })
+    }
 
-impl AsyncModel {
-    /// # Panics
-    /// This function will panic if the `model` does not have sole ownership over its `Env`.  This means
-    /// the `Model` cannot be created with [`Model::new`], instead you must use [`Model::with_env`].
-    /// # Examples
+    // Call a Gurobi routine filling a sparse vector of at most `n` nonzeros
+    fn get_svec(&self, n: usize, f: impl FnOnce(*mut ffi::GRBsvec) -> c_int) -> Result<SparseVec> {
+        if self.is_mip()? || self.status()? != Status::Optimal {
+            return Err(Error::DataNotAvailable(
+                "simplex tableau requires an LP solved to optimality".to_owned(),
+            ));
+        }
+        let mut inds = vec![0; n];
+        let mut vals = vec![0.0; n];
+        let mut svec = ffi::GRBsvec {
+            len: 0,
+            ind: inds.as_mut_ptr(),
+            val: vals.as_mut_ptr(),
+        };
+        self.check_apicall(f(&mut svec))?;
+        inds.truncate(svec.len as usize);
+        vals.truncate(svec.len as usize);
+        Ok(SparseVec { inds, vals })
+    }
+
+    /// Collect the solution, dual information, sensitivity ranges and basis of a solved LP into a single
+    /// [`SolveReport`].  Each attribute is queried with a single batch call for all variables or all constraints.
     ///
-    /// This example panics because `env` has two references - inside `m` and the bound variable in the current scope
-    /// ```should_panic
-    /// use grb::prelude::*;
-    /// use grb::AsyncModel;
+    /// # Errors
+    /// - [`Error::ModelUpdateNeeded`] if the model has pending changes.
+    /// - [`Error::FromAPI`] if the model has not been solved to optimality, or is not a continuous model
+    ///   (dual values, sensitivity ranges and the basis are only available for LPs).
+    pub fn export_report(&self) -> Result<SolveReport> {
+        let vars = self.get_vars()?.to_vec();
+        let constrs = self.get_constrs()?.to_vec();
+        let var_attr = |a| self.get_obj_attr_batch(a, vars.iter().copied());
+        let constr_attr = |a| self.get_obj_attr_batch(a, constrs.iter().copied());
+
+        let obj_val = self.get_attr(attr::ObjVal)?;
+        let x = var_attr(attr::X)?;
+        let reduced_costs = var_attr(attr::RC)?;
+        let obj_ranges = var_attr(attr::SAObjLow)?
+            .into_iter()
+            .zip(var_attr(attr::SAObjUp)?)
+            .collect();
+        let vbasis = self.get_obj_attr_batch(attr::VBasis, vars.iter().copied())?;
+        let duals = constr_attr(attr::Pi)?;
+        let slacks = constr_attr(attr::Slack)?;
+        let rhs_ranges = constr_attr(attr::SARHSLow)?
+            .into_iter()
+            .zip(constr_attr(attr::SARHSUp)?)
+            .collect();
+        let cbasis = self.get_obj_attr_batch(attr::CBasis, constrs.iter().copied())?;
+
+        Ok(SolveReport {
+            obj_val,
+            vars,
+            x,
+            reduced_costs,
+            obj_ranges,
+            vbasis,
+            constrs,
+            duals,
+            slacks,
+            rhs_ranges,
+            cbasis,
+        })
+    }
+
+    /// Retrieve statistics about the work done by the most recent optimisation.  See [`SolveStats`].
     ///
-    /// let env = Env::new("")?;
-    /// let mut m = Model::with_env("model", &env)?;
-    /// let mut m =  AsyncModel::new(m); // panic - env is still in scope
-    /// # Ok::<(), grb::Error>(())
-    /// ```
-    /// This is easily resolved by ensuring `env` is no longer in scope when the `AsyncModel` is created.
-    /// ```
-    /// # use grb::prelude::*;
-    /// # use grb::AsyncModel;
-    /// # let env = Env::new("")?;
-    /// let mut m = Model::with_env("model", &env)?;
-    /// drop(env);
-    /// let mut m =  AsyncModel::new(m); // ok
-    /// # Ok::<(), grb::Error>(())
-    /// ```
-    /// You can also pass an owned `Env` to `Model::with_env`:
-    /// ```
-    /// # use grb::prelude::*;
-    /// # use grb::AsyncModel;
-    /// # let env = Env::new("")?;
-    /// let mut m = Model::with_env("model", env)?;
-    /// let mut m =  AsyncModel::new(m); // also ok
-    /// # Ok::<(), grb::Error>(())
+    /// # Errors
+    /// Returns [`Error::FromAPI`] if the model has not been optimised.
+    pub fn solve_stats(&self) -> Result<SolveStats> {
+        let node_count = if self.is_mip()? {
+            self.get_attr(attr::NodeCount)?
+        } else {
+            0.0
+        };
+        Ok(SolveStats {
+            node_count,
+            iter_count: self.get_attr(attr::IterCount)?,
+            bar_iter_count: self.get_attr(attr::BarIterCount)? as usize,
+            sol_count: self.get_attr(attr::SolCount)? as usize,
+        })
+    }
+
+    /// Number of variables in the model (the `NumVars` attribute).
+    pub fn num_vars(&self) -> Result<usize> {
+        Ok(self.get_attr(attr::NumVars)? as usize)
+    }
+
+    /// Number of linear constraints in the model (the `NumConstrs` attribute).
+    pub fn num_constrs(&self) -> Result<usize> {
+        Ok(self.get_attr(attr::NumConstrs)? as usize)
+    }
+
+    /// Number of quadratic constraints in the model (the `NumQConstrs` attribute).
+    pub fn num_qconstrs(&self) -> Result<usize> {
+        Ok(self.get_attr(attr::NumQConstrs)? as usize)
+    }
+
+    /// Number of general constraints in the model (the `NumGenConstrs` attribute).
+    pub fn num_genconstrs(&self) -> Result<usize> {
+        Ok(self.get_attr(attr::NumGenConstrs)? as usize)
+    }
+
+    /// Number of SOS constraints in the model (the `NumSOS` attribute).
+    pub fn num_sos(&self) -> Result<usize> {
+        Ok(self.get_attr(attr::NumSOS)? as usize)
+    }
+
+    fn check_is_mip(&self, attrname: &str) -> Result<()> {
+        if !self.is_mip()? {
+            return Err(Error::DataNotAvailable(format!(
+                "{attrname} is only available for MIP models"
+            )));
+        }
+        Ok(())
+    }
+
+    /// Retrieve the current relative MIP optimality gap (the `MIPGap` attribute).
+    ///
+    /// # Examples
     /// ```
-    /// This example panics because `m` uses the default `Env`, which is also stored globally.
-    /// `Model`s created with [`Model::new`] can never be made into `AsyncModel`s for this reason.
-    /// ```should_panic
     /// # use grb::prelude::*;
-    /// # use grb::AsyncModel;
-    /// let m = Model::new("model1")?;
-    /// let m =  AsyncModel::new(m); // panic
+    /// let mut m = Model::new("model")?;
+    /// let x = add_intvar!(m, bounds: 0..10)?;
+    /// m.set_objective(x, Maximize)?;
+    /// m.optimize()?;
+    /// assert_eq!(m.mip_gap()?, 0.0);
     /// # Ok::<(), grb::Error>(())
     /// ```
     ///
-    pub fn new(model: Model) -> AsyncModel {
-        assert!(
-            !model.env.is_shared(),
-            "Cannot create async model - environment is used in other models"
-        );
-        AsyncModel(model)
+    /// # Errors
+    /// - [`Error::DataNotAvailable`] if the model is not a MIP.
+    /// - [`Error::FromAPI`] if no solve has been performed.
+    pub fn mip_gap(&self) -> Result<f64> {
+        self.check_is_mip("MIPGap")?;
+        self.get_attr(attr::MIPGap)
     }
 
-    /// Optimize the model on another thread.  This method will always trigger a [`Model::update`] on the underlying `Model`.
+    /// Retrieve the best known bound on the optimal objective (the `ObjBound` attribute).
     ///
-    /// On success, returns an [`AsyncHandle`] that provides a limited API for model queries.
-    /// The `AsyncModel` can be retrieved by calling [`AsyncHandle::join`](crate::AsyncHandle::join).
+    /// # Errors
+    /// - [`Error::DataNotAvailable`] if the model is not a MIP.
+    /// - [`Error::FromAPI`] if no solve has been performed.
+    pub fn obj_bound(&self) -> Result<f64> {
+        self.check_is_mip("ObjBound")?;
+        self.get_attr(attr::ObjBound)
+    }
+
+    /// Retrieve the best known bound on the optimal objective (the `ObjBoundC` attribute).  Unlike
+    /// [`Model::obj_bound`], this bound is not rounded up to the nearest integer when the objective
+    /// is known to take integral values.
     ///
     /// # Errors
-    /// An `grb::Error::FromAPI` may occur.  In this case, the `Err` variant contains this error
-    /// and gives back ownership of this `AsyncModel`.
+    /// - [`Error::DataNotAvailable`] if the model is not a MIP.
+    /// - [`Error::FromAPI`] if no solve has been performed.
+    pub fn obj_bound_c(&self) -> Result<f64> {
+        self.check_is_mip("ObjBoundC")?;
+        self.get_attr(attr::ObjBoundC)
+    }
+
+    /// Retrieve the condition number of the optimal simplex basis (the `Kappa` or `KappaExact` attribute).
     ///
-    /// # Examples
-    /// ```
-    /// use grb::prelude::*;
-    /// use grb::AsyncModel;
+    /// If `exact` is `false`, a cheap estimate is returned.  Computing the exact condition number
+    /// can be expensive for large models.
     ///
-    /// let mut m = Model::with_env("model", &Env::new("")?)?;
-    /// let x = add_ctsvar!(m, obj: 2)?;
-    /// let y = add_intvar!(m, bounds: 0..100)?;
-    /// m.add_constr("c0", c!(x <= y - 0.5 ))?;
-    /// let m = AsyncModel::new(m);
+    /// # Errors
+    /// Returns [`Error::DataNotAvailable`] if the model is a MIP or has not been solved to optimality, since
+    /// no optimal basis is available.
+    pub fn condition_number(&self, exact: bool) -> Result<f64> {
+        if self.is_mip()? || self.status()? != Status::Optimal {
+            return Err(Error::DataNotAvailable(
+                "condition number requires an LP solved to optimality".to_owned(),
+            ));
+        }
+        if exact {
+            self.get_attr(attr::KappaExact)
+        } else {
+            self.get_attr(attr::Kappa)
+        }
+    }
+
+    impl_object_list_getter!(get_vars, Var, vars, "variables");
+
+    impl_object_list_getter!(get_constrs, Constr, constrs, "constraints");
+
+    impl_object_list_getter!(get_genconstrs, GenConstr, genconstrs, "general constraints");
+
+    impl_object_list_getter!(get_qconstrs, QConstr, qconstrs, "quadratic constraints");
+
+    impl_object_list_getter!(get_sos, SOS, sos, "SOS constraints");
+
+    /// Retrieve the name and solution value of every variable in the model, in the same order as
+    /// [`Model::get_vars`].  The names and values are each fetched with a single batch query.
     ///
-    /// let handle = match m.optimize() {
-    ///   Err((_, e)) => panic!("{}", e),
-    ///   Ok(h) => h
-    /// };
+    /// # Errors
+    /// - [`Error::ModelUpdateNeeded`] if a model update is needed
+    /// - [`Error::DataNotAvailable`] if no solution is available
+    pub fn solution_map(&self) -> Result<Vec<(String, f64)>> {
+        let vars = self.get_vars()?;
+        if self.get_attr(attr::SolCount)? == 0 {
+            return Err(Error::DataNotAvailable("no solution available".to_string()));
+        }
+        let names = self.get_obj_attr_batch(attr::VarName, vars.iter().copied())?;
+        let values = self.get_obj_attr_batch(attr::X, vars.iter().copied())?;
+        Ok(names.into_iter().zip(values).collect())
+    }
+
+    /// Assemble the current solution as a JSON value, following the schema of Gurobi's JSON solution files
+    /// (see the [manual](https://docs.gurobi.com/projects/optimizer/en/current/reference/fileformats/modelsolutionfiles.html)).
+    /// The value is built from attribute queries, so no file is written.  Available with the `serde` feature.
     ///
-    /// println!("The model has explored {} MIP nodes so far", handle.node_cnt()?);
-    /// let (m, errors) = handle.join(); // the AsyncModel is always returned
-    /// errors?; // optimisation errors - as if Model::optimize were called.
-    /// let m: Model = m.into(); // get original Model back
+    /// The `SolutionInfo` object contains the `Status`, `Runtime`, `ObjVal` and `SolCount` attributes, along with
+    /// `ObjBound`, `MIPGap` and `NodeCount` for MIP models.  The `Vars` array contains the `VarName` and `X`
+    /// attributes of every variable.
+    ///
+    /// # Errors
+    /// See [`Model::solution_map`].
+    #[cfg(feature = "serde")]
+    pub fn solution_json(&self) -> Result<serde_json::Value> {
+        use serde_json::json;
+        let vars: Vec<_> = self
+            .solution_map()?
+            .into_iter()
+            .map(|(name, x)| json!({ "VarName": name, "X": x }))
+            .collect();
+        let mut info = json!({
+            "Status": self.status()? as i32,
+            "Runtime": self.get_attr(attr::Runtime)?,
+            "ObjVal": self.get_attr(attr::ObjVal)?,
+            "SolCount": self.get_attr(attr::SolCount)?,
+        });
+        if self.get_attr(attr::IsMIP)? == 1 {
+            info["ObjBound"] = json!(self.get_attr(attr::ObjBound)?);
+            info["MIPGap"] = json!(self.get_attr(attr::MIPGap)?);
+            info["NodeCount"] = json!(self.get_attr(attr::NodeCount)?);
+        }
+        Ok(json!({ "SolutionInfo": info, "Vars": vars }))
+    }
+
+    /// Iterate over the variables in the model, in order.  The handles are copied out of the model,
+    /// so the model may be modified while iterating.
+    ///
+    /// # Examples
+    /// ```
+    /// # use grb::prelude::*;
+    /// let mut m = Model::new("model")?;
+    /// add_binvar!(m)?;
+    /// add_binvar!(m)?;
+    /// m.update()?;
+    /// for v in m.vars()? {
+    ///   m.set_obj_attr(attr::Obj, &v, 1.0)?;
+    /// }
     /// # Ok::<(), grb::Error>(())
     /// ```
-    pub fn optimize(mut self) -> std::result::Result<AsyncHandle, (Self, Error)> {
-        match self.0.update().and_then(|()| {
-            self.0
-                .check_apicall(unsafe { ffi::GRBoptimizeasync(self.0.ptr) })
-        }) {
-            Ok(()) => Ok(AsyncHandle(self.0)),
-            Err(e) => Err((self, e)),
+    ///
+    /// # Errors
+    /// Returns an error if a model update is needed
+    pub fn vars(&self) -> Result<impl Iterator<Item = Var>> {
+        Ok(self.get_vars()?.to_vec().into_iter())
+    }
+
+    /// Iterate over the linear constraints in the model, in order.  The handles are copied out of the model,
+    /// so the model may be modified while iterating.
+    ///
+    /// # Errors
+    /// Returns an error if a model update is needed
+    pub fn constrs(&self) -> Result<impl Iterator<Item = Constr>> {
+        Ok(self.get_constrs()?.to_vec().into_iter())
+    }
+
+    /// Remove a variable or constraint from the model.
+    ///
+    /// In lazy update mode (`UpdateMode` parameter set to 0), removals are queued and passed to Gurobi in a
+    /// single call per object type at the next [`Model::update`], so removing objects in a loop is cheap.
+    /// Otherwise, the object is removed from the Gurobi model immediately.
+    pub fn remove<O: ModelObject>(&mut self, item: O) -> Result<()> {
+        let lazy = self.update_mode_lazy()?;
+        let im = O::idx_manager_mut(self);
+        let idx = im.get_index(&item)?;
+        im.remove(item, lazy)?;
+        if lazy {
+            Ok(())
+        } else {
+            self.check_apicall(unsafe { O::gurobi_remove(self.ptr, &[idx]) })
+        }
+    }
+
+    // delete all objects of type `O` whose removal was deferred in a single call.  The queue is only cleared
+    // once Gurobi has accepted the removals, so a failed flush can be retried by the next update.
+    fn flush_deferred_removals<O: ModelObject>(&mut self) -> Result<()> {
+        let inds = O::idx_manager(self).deferred_removals();
+        if inds.is_empty() {
+            return Ok(());
         }
+        self.check_apicall(unsafe { O::gurobi_remove(self.ptr, inds) })?;
+        O::idx_manager_mut(self).clear_deferred_removals();
+        Ok(())
+    }
+
+    /// Retrieve a single constant matrix coefficient of the model.
+    pub fn get_coeff(&self, var: &Var, constr: &Constr) -> Result<f64> {
+        let mut value = 0.0;
+        self.check_apicall(unsafe {
+            ffi::GRBgetcoeff(
+                self.ptr,
+                self.get_index_build(constr)?,
+                self.get_index_build(var)?,
+                &mut value,
+            )
+        })?;
+        Ok(value)
+    }
+
+    /// Change a single constant matrix coefficient of the model.
+    ///
+    /// Setting a coefficient for a variable which does not yet appear in the constraint adds a new nonzero to
+    /// the constraint matrix, and setting an existing coefficient to `0.0` removes it.  As with other model
+    /// modifications, the change is only visible (for example in the `NumNZs` attribute) after the next update.
+    pub fn set_coeff(&mut self, var: &Var, constr: &Constr, value: f64) -> Result<()> {
+        self.check_apicall(unsafe {
+            ffi::GRBchgcoeffs(
+                self.ptr,
+                1,
+                &self.get_index_build(constr)?,
+                &self.get_index_build(var)?,
+                &value,
+            )
+        })
+    }
+
+    /// Change a set of constant matrix coefficients of the model.  See [`Model::set_coeff`] for how new and
+    /// zero coefficients are handled.  In particular, setting a coefficient which is not in the constraint matrix
+    /// to `0.0` leaves the matrix unchanged, so dense data with many zeros can be passed directly.
+    pub fn set_coeffs(
+        &mut self,
+        coeffs: impl IntoIterator<Item = (Var, Constr, f64)>,
+    ) -> Result<()> {
+        let (vind, cind, val) = self.build_idx_arrays_obj_obj(coeffs.into_iter())?;
+        self.check_apicall(unsafe {
+            ffi::GRBchgcoeffs(
+                self.as_mut_ptr(),
+                vind.len() as ffi::c_int,
+                cind.as_ptr(),
+                vind.as_ptr(),
+                val.as_ptr(),
+            )
+        })
+    }
+
+    // retrieve the variable indices and coefficients of a linear constraint's row
+    fn get_constr_coeffs(&self, constr: &Constr) -> Result<(Vec<i32>, Vec<f64>)> {
+        let cidx = self.get_index(constr)?;
+        let mut numnz = 0;
+        let mut cbeg = 0;
+        self.check_apicall(unsafe {
+            ffi::GRBgetconstrs(
+                self.ptr,
+                &mut numnz,
+                null_mut(),
+                null_mut(),
+                null_mut(),
+                cidx,
+                1,
+            )
+        })?;
+        let mut inds = vec![0; numnz as usize];
+        let mut vals = vec![0.0; numnz as usize];
+        self.check_apicall(unsafe {
+            ffi::GRBgetconstrs(
+                self.ptr,
+                &mut numnz,
+                &mut cbeg,
+                inds.as_mut_ptr(),
+                vals.as_mut_ptr(),
+                cidx,
+                1,
+            )
+        })?;
+        Ok((inds, vals))
+    }
+
+    /// Retrieve the row of a linear constraint, returning the left-hand side expression, the sense and the
+    /// right-hand side.  This is useful to inspect constraints of models read with [`Model::from_file`].
+    ///
+    /// # Example
+    /// ```
+    /// # use grb::prelude::*;
+    /// let mut m = Model::new("model")?;
+    /// let x = add_ctsvar!(m)?;
+    /// let y = add_ctsvar!(m)?;
+    /// let c = m.add_constr("c", c!(x + 2*y >= 3))?;
+    /// m.update()?;
+    /// let (lhs, sense, rhs) = m.get_linear_constr_expr(&c)?;
+    /// assert_eq!(lhs.num_terms(), 2);
+    /// assert_eq!(rhs, 3.0);
+    /// # Ok::<(), grb::Error>(())
+    /// ```
+    ///
+    /// # Errors
+    /// - [`Error::ModelUpdateNeeded`] if the model has pending changes.
+    /// - [`Error::ModelObjectRemoved`] if the constraint has been removed from the model.
+    /// - [`Error::ModelObjectMismatch`] if the constraint is from a different model.
+    pub fn get_linear_constr_expr(&self, c: &Constr) -> Result<(LinExpr, ConstrSense, f64)> {
+        let vars = self.get_vars()?;
+        if self.constrs.model_update_needed() {
+            return Err(Error::ModelUpdateNeeded);
+        }
+        let (inds, coeffs) = self.get_constr_coeffs(c)?;
+        let mut expr = LinExpr::new();
+        for (i, a) in inds.into_iter().zip(coeffs) {
+            expr.add_term(a, vars[i as usize]);
+        }
+        let sense = self.get_obj_attr(attr::Sense, c)?;
+        let rhs = self.get_obj_attr(attr::RHS, c)?;
+        Ok((expr, sense, rhs))
+    }
+
+    /// Retrieve a quadratic constraint, returning the left-hand side expression (linear and quadratic terms),
+    /// the sense and the right-hand side.  See also [`Model::get_linear_constr_expr`].
+    ///
+    /// # Errors
+    /// - [`Error::ModelUpdateNeeded`] if the model has pending changes.
+    /// - [`Error::ModelObjectRemoved`] if the constraint has been removed from the model.
+    /// - [`Error::ModelObjectMismatch`] if the constraint is from a different model.
+    /// - [`Error::NotYetSupported`] if Gurobi reports a constraint sense unknown to this crate.
+    pub fn get_q_constr_expr(&self, qc: &QConstr) -> Result<(QuadExpr, ConstrSense, f64)> {
+        let vars = self.get_vars()?;
+        if self.qconstrs.model_update_needed() {
+            return Err(Error::ModelUpdateNeeded);
+        }
+        let idx = self.get_index(qc)?;
+        let mut numlnz = 0;
+        let mut numqnz = 0;
+        self.check_apicall(unsafe {
+            ffi::GRBgetqconstr(
+                self.ptr,
+                idx,
+                &mut numlnz,
+                null_mut(),
+                null_mut(),
+                &mut numqnz,
+                null_mut(),
+                null_mut(),
+                null_mut(),
+            )
+        })?;
+        let mut lind = vec![0; numlnz as usize];
+        let mut lval = vec![0.0; numlnz as usize];
+        let mut qrow = vec![0; numqnz as usize];
+        let mut qcol = vec![0; numqnz as usize];
+        let mut qval = vec![0.0; numqnz as usize];
+        self.check_apicall(unsafe {
+            ffi::GRBgetqconstr(
+                self.ptr,
+                idx,
+                &mut numlnz,
+                lind.as_mut_ptr(),
+                lval.as_mut_ptr(),
+                &mut numqnz,
+                qrow.as_mut_ptr(),
+                qcol.as_mut_ptr(),
+                qval.as_mut_ptr(),
+            )
+        })?;
+
+        let mut expr = QuadExpr::new();
+        for (i, a) in lind.into_iter().zip(lval) {
+            expr.add_term(a, vars[i as usize]);
+        }
+        for ((i, j), a) in qrow.into_iter().zip(qcol).zip(qval) {
+            expr.add_qterm(a, vars[i as usize], vars[j as usize]);
+        }
+        let sense = ConstrSense::try_from(self.get_obj_attr(attr::QCSense, qc)?)
+            .map_err(Error::NotYetSupported)?;
+        let rhs = self.get_obj_attr(attr::QCRHS, qc)?;
+        Ok((expr, sense, rhs))
+    }
+
+    /// Compute the activity (left-hand side value) of a linear constraint at the current solution.
+    ///
+    /// The activity is computed from the constraint's coefficients and the `X` attribute of its
+    /// variables, so for a `<=` constraint it is equal to `rhs - slack`, where `slack` is the
+    /// `Slack` attribute of the constraint.
+    ///
+    /// # Errors
+    /// - [`Error::ModelObjectPending`] if the constraint hasn't yet been added to the model.
+    /// - [`Error::FromAPI`] if no solution is available.
+    pub fn constr_activity(&self, constr: &Constr) -> Result<f64> {
+        let (inds, coeffs) = self.get_constr_coeffs(constr)?;
+        let mut x = vec![0.0; inds.len()];
+        self.check_apicall(unsafe {
+            ffi::GRBgetdblattrlist(
+                self.ptr,
+                attr::X.as_cstr().as_ptr(),
+                inds.len() as c_int,
+                inds.as_ptr(),
+                x.as_mut_ptr(),
+            )
+        })?;
+        Ok(coeffs.iter().zip(&x).map(|(a, x)| a * x).sum())
+    }
+
+    /// Check the current solution against the linear constraints of the model on the client side.
+    ///
+    /// The activity of each linear constraint is computed with [`Model::constr_activity`] and compared to its
+    /// right-hand side.  Every constraint violated by more than `tol` is returned, so an empty vector means
+    /// the solution reported by Gurobi checks out.  Quadratic, general and SOS constraints, and variable bounds
+    /// are not checked.
+    ///
+    /// # Errors
+    /// - [`Error::ModelUpdateNeeded`] if the model has pending changes.
+    /// - [`Error::FromAPI`] if no solution is available.
+    pub fn verify_solution(&self, tol: f64) -> Result<Vec<Violation>> {
+        let constrs = self.get_constrs()?;
+        let rhs = self.get_obj_attr_batch(attr::RHS, constrs.iter().copied())?;
+        let senses = self.get_obj_attr_batch(attr::Sense, constrs.iter().copied())?;
+        let mut violations = Vec::new();
+        for ((&constr, rhs), sense) in constrs.iter().zip(rhs).zip(senses) {
+            let activity = self.constr_activity(&constr)?;
+            let amount = match sense {
+                ConstrSense::Less => activity - rhs,
+                ConstrSense::Greater => rhs - activity,
+                ConstrSense::Equal => (activity - rhs).abs(),
+            };
+            if amount > tol {
+                violations.push(Violation {
+                    constr,
+                    sense,
+                    rhs,
+                    activity,
+                    amount,
+                });
+            }
+        }
+        Ok(violations)
+    }
+
+    // add quadratic terms of objective function.
+    fn add_qpterms(&mut self, qrow: &[i32], qcol: &[i32], qval: &[f64]) -> Result<()> {
+        self.check_apicall(unsafe {
+            ffi::GRBaddqpterms(
+                self.ptr,
+                qrow.len() as ffi::c_int,
+                qrow.as_ptr(),
+                qcol.as_ptr(),
+                qval.as_ptr(),
+            )
+        })
+    }
+
+    // remove quadratic terms of objective function.
+    fn del_qpterms(&mut self) -> Result<()> {
+        self.check_apicall(unsafe { ffi::GRBdelq(self.ptr) })
+    }
+
+    pub(crate) fn check_apicall(&self, error: ffi::c_int) -> Result<()> {
+        if error != 0 {
+            return Err(self.env.error_from_api(error));
+        }
+        Ok(())
+    }
+}
+
+// return an error if any of `vals` are NaN
+fn check_no_nan(vals: &[f64], what: &str) -> Result<()> {
+    if vals.iter().any(|v| v.is_nan()) {
+        return Err(Error::AlgebraicError(format!("{what} must not be NaN")));
+    }
+    Ok(())
+}
+
+// return an error if an SOS constraint of type `sostype` cannot have `n` members
+fn check_sos_size(sostype: SOSType, n: usize) -> Result<()> {
+    let min = match sostype {
+        SOSType::Ty1 => 1,
+        SOSType::Ty2 => 2,
+    };
+    if n < min {
+        return Err(Error::AlgebraicError(format!(
+            "{sostype:?} SOS constraint needs at least {min} members, got {n}"
+        )));
+    }
+    Ok(())
+}
+
+// Look for the lines Gurobi logs about the user MIP start
+fn parse_mip_start_used(log: &str) -> Option<bool> {
+    let mut used = None;
+    for line in log.lines() {
+        if line.starts_with("Loaded user MIP start")
+            || line.starts_with("User MIP start produced solution")
+        {
+            used = Some(true);
+        } else if line.starts_with("User MIP start did not produce")
+            || line.starts_with("User MIP start violates")
+            || line.starts_with("User MIP start is infeasible")
+        {
+            used = Some(false);
+        }
+    }
+    used
+}
+
+impl Drop for Model {
+    fn drop(&mut self) {
+        // Note: This method runs *before* the `drop()` method on the env inside the model
+        // so we free the GRBModel before the GRBEnv, as per the Gurobi docs.
+        unsafe { ffi::GRBfreemodel(self.ptr) };
+    }
+}
+
+/// Selects the part of a model to export with [`Model::write_subset`].
+#[derive(Debug, Clone)]
+pub enum SubsetSpec {
+    /// All variables and the objective function, but no constraints
+    ObjectiveOnly,
+    /// All variables, the objective function and the given linear constraints.  All other constraints
+    /// (including quadratic, general and SOS constraints) are omitted.
+    Constrs(Vec<Constr>),
+    /// The given variables and all constraints.  Other variables are omitted, along with their
+    /// objective and constraint coefficients.
+    Vars(Vec<Var>),
+}
+
+/// A sparse vector, returned by [`Model::tableau_row`] and [`Model::tableau_col`].
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SparseVec {
+    /// Indices of the nonzero entries
+    pub inds: Vec<i32>,
+    /// Values of the nonzero entries
+    pub vals: Vec<f64>,
+}
+
+/// Everything about the solution of an LP, returned by [`Model::export_report`].
+///
+/// The variable data is given in the order of [`SolveReport::vars`], and the constraint data in the order of
+/// [`SolveReport::constrs`].
+#[derive(Debug, Clone)]
+pub struct SolveReport {
+    /// The objective value (the `ObjVal` attribute)
+    pub obj_val: f64,
+    /// All variables in the model
+    pub vars: Vec<Var>,
+    /// Solution values (the `X` attribute)
+    pub x: Vec<f64>,
+    /// Reduced costs (the `RC` attribute)
+    pub reduced_costs: Vec<f64>,
+    /// Objective coefficient sensitivity ranges (the `SAObjLow` and `SAObjUp` attributes)
+    pub obj_ranges: Vec<(f64, f64)>,
+    /// Basis status of the variables (the `VBasis` attribute)
+    pub vbasis: Vec<i32>,
+    /// All linear constraints in the model
+    pub constrs: Vec<Constr>,
+    /// Dual values (the `Pi` attribute)
+    pub duals: Vec<f64>,
+    /// Constraint slacks (the `Slack` attribute)
+    pub slacks: Vec<f64>,
+    /// Right-hand side sensitivity ranges (the `SARHSLow` and `SARHSUp` attributes)
+    pub rhs_ranges: Vec<(f64, f64)>,
+    /// Basis status of the constraints (the `CBasis` attribute)
+    pub cbasis: Vec<i32>,
+}
+
+/// The members of an Irreducible Inconsistent Subsystem (IIS), returned by [`Model::get_iis`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Iis {
+    /// Linear constraints in the IIS (the `IISConstr` attribute)
+    pub constrs: Vec<Constr>,
+    /// Quadratic constraints in the IIS (the `IISQConstr` attribute)
+    pub qconstrs: Vec<QConstr>,
+    /// SOS constraints in the IIS (the `IISSOS` attribute)
+    pub sos: Vec<SOS>,
+    /// General constraints in the IIS (the `IISGenConstr` attribute)
+    pub genconstrs: Vec<GenConstr>,
+    /// Variables whose lower bound is in the IIS (the `IISLB` attribute)
+    pub lb_vars: Vec<Var>,
+    /// Variables whose upper bound is in the IIS (the `IISUB` attribute)
+    pub ub_vars: Vec<Var>,
+}
+
+/// Solver statistics for the most recent optimisation, returned by [`Model::solve_stats`].
+///
+/// Cut counts are not available as attributes, only in the log.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SolveStats {
+    /// Number of branch-and-cut nodes explored (the `NodeCount` attribute).  Zero for continuous models.
+    pub node_count: f64,
+    /// Number of simplex iterations performed (the `IterCount` attribute)
+    pub iter_count: f64,
+    /// Number of barrier iterations performed (the `BarIterCount` attribute)
+    pub bar_iter_count: usize,
+    /// Number of solutions found (the `SolCount` attribute)
+    pub sol_count: usize,
+}
+
+/// A feasibility relaxation with the slack variables of each penalized constraint, returned by
+/// [`Model::feas_relax_mapped`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct FeasRelaxSlacks {
+    /// The objective value of the relaxation, as returned by [`Model::feas_relax`]
+    pub feas_obj: Option<f64>,
+    /// Each penalized constraint paired with one of its slack variables, in the order they were given
+    pub slacks: Vec<(Constr, Var)>,
+}
+
+/// A linear constraint violated by the current solution, as found by [`Model::verify_solution`].
+#[derive(Debug, Clone, Copy)]
+pub struct Violation {
+    /// The violated constraint
+    pub constr: Constr,
+    /// The sense of the constraint
+    pub sense: ConstrSense,
+    /// The right-hand side of the constraint
+    pub rhs: f64,
+    /// The activity (left-hand side value) of the constraint at the current solution
+    pub activity: f64,
+    /// The amount by which the constraint is violated (always positive)
+    pub amount: f64,
+}
+
+/// Accumulates an objective function from several fragments, created with [`Model::objective_builder`].
+///
+/// Fragments are added with `+=`, and the objective is set with a single call to [`Model::set_objective`]
+/// by [`ObjectiveBuilder::set`].  Dropping the builder without calling `set` leaves the objective unchanged.
+pub struct ObjectiveBuilder<'a> {
+    model: &'a mut Model,
+    expr: Expr,
+}
+
+impl ObjectiveBuilder<'_> {
+    /// Set the accumulated expression as the objective function of the model.  See [`Model::set_objective`].
+    pub fn set<S>(self, sense: S) -> Result<()>
+    where
+        S: TryInto<ModelSense>,
+        S::Error: std::fmt::Display,
+    {
+        self.model.set_objective(self.expr, sense)
+    }
+}
+
+impl<T: Into<Expr>> std::ops::AddAssign<T> for ObjectiveBuilder<'_> {
+    fn add_assign(&mut self, rhs: T) {
+        self.expr = std::mem::take(&mut self.expr) + rhs.into();
+    }
+}
+
+/// A handle to an [`AsyncModel`] which is currently solving.
+pub struct AsyncHandle(Model);
+
+impl AsyncHandle {
+    /// Retrieve current the `attr::Status` of the model.
+    pub fn status(&self) -> Result<Status> {
+        self.0.status()
+    }
+
+    /// Retrieve the current `attr::ObjVal` of the model.
+    pub fn obj_val(&self) -> Result<f64> {
+        self.0.get_attr(attr::ObjVal)
+    }
+
+    /// Retrieve the current  `attr::ObjBound` of the model.
+    pub fn obj_bnd(&self) -> Result<f64> {
+        self.0.get_attr(attr::ObjBound)
+    }
+
+    /// Retrieve the current `attr::IterCount` of the model.
+    pub fn iter_cnt(&self) -> Result<f64> {
+        self.0.get_attr(attr::IterCount)
+    }
+
+    /// Retrieve the current `attr::BarIterCount` of the model.
+    pub fn bar_iter_cnt(&self) -> Result<i32> {
+        self.0.get_attr(attr::BarIterCount)
+    }
+
+    /// Retrieve the current `attr::NodeCount` of the model.
+    pub fn node_cnt(&self) -> Result<f64> {
+        self.0.get_attr(attr::NodeCount)
+    }
+
+    /// Wait for optimisation to finish.
+    ///
+    /// # Errors
+    /// An [`Error::FromAPI`] may occur during optimisation, in which case it is stored in the `Result`.
+    pub fn join(self) -> (AsyncModel, Result<()>) {
+        let errors = self.0.check_apicall(unsafe { ffi::GRBsync(self.0.ptr) });
+        (AsyncModel(self.0), errors)
+    }
+
+    /// Send a request to Gurobi to terminate optimization.  Optimization may not finish immediately.
+    ///
+    /// # Example
+    /// ```
+    /// # use grb::prelude::*;
+    /// use grb::AsyncModel;
+    /// let e = Env::new("")?;
+    /// let m = Model::with_env("async", e)?;
+    /// # /*
+    ///   ...
+    /// # */
+    /// let m = AsyncModel::new(m);
+    /// // discard `AsyncModel` on failure and panic
+    /// let handle = m.optimize().map_err(|(_, e)| e).unwrap();
+    /// # /*
+    ///   ...
+    /// # */
+    /// handle.terminate();
+    /// # let (m, errors) = handle.join();
+    /// # errors?;
+    /// # Ok::<(), grb::Error>(())
+    /// ```
+    pub fn terminate(&self) {
+        self.0.terminate();
+    }
+}
+
+/// A wrapper around [`Model`] that supports async optimisation in the background.
+///
+///  From the Gurobi [manual](https://www.gurobi.com/documentation/9.1/refman/c_optimizeasync.html), regarding solving models asynchronously:
+///
+/// *"[modifying or performing non-permitted] calls on the running model, **or on any other models that were built within the same Gurobi environment**,
+///  will fail with error code `OPTIMIZATION_IN_PROGRESS`."*
+///
+/// For this reason, creating an `AsyncModel` requires a [`Model`] whose [`Env`] wasn't previously been used to construct other models.
+///
+/// `Model` implements `From<AsyncModel>`, so you can recover the `Model` using `.into()` (see examples).
+pub struct AsyncModel(Model);
+
+impl AsyncModel {
+    /// # Panics
+    /// This function will panic if the `model` does not have sole ownership over its `Env`.  This means
+    /// the `Model` cannot be created with [`Model::new`], instead you must use [`Model::with_env`].
+    /// # Examples
+    ///
+    /// This example panics because `env` has two references - inside `m` and the bound variable in the current scope
+    /// ```should_panic
+    /// use grb::prelude::*;
+    /// use grb::AsyncModel;
+    ///
+    /// let env = Env::new("")?;
+    /// let mut m = Model::with_env("model", &env)?;
+    /// let mut m =  AsyncModel::new(m); // panic - env is still in scope
+    /// # Ok::<(), grb::Error>(())
+    /// ```
+    /// This is easily resolved by ensuring `env` is no longer in scope when the `AsyncModel` is created.
+    /// ```
+    /// # use grb::prelude::*;
+    /// # use grb::AsyncModel;
+    /// # let env = Env::new("")?;
+    /// let mut m = Model::with_env("model", &env)?;
+    /// drop(env);
+    /// let mut m =  AsyncModel::new(m); // ok
+    /// # Ok::<(), grb::Error>(())
+    /// ```
+    /// You can also pass an owned `Env` to `Model::with_env`:
+    /// ```
+    /// # use grb::prelude::*;
+    /// # use grb::AsyncModel;
+    /// # let env = Env::new("")?;
+    /// let mut m = Model::with_env("model", env)?;
+    /// let mut m =  AsyncModel::new(m); // also ok
+    /// # Ok::<(), grb::Error>(())
+    /// ```
+    /// This example panics because `m` uses the default `Env`, which is also stored globally.
+    /// `Model`s created with [`Model::new`] can never be made into `AsyncModel`s for this reason.
+    /// ```should_panic
+    /// # use grb::prelude::*;
+    /// # use grb::AsyncModel;
+    /// let m = Model::new("model1")?;
+    /// let m =  AsyncModel::new(m); // panic
+    /// # Ok::<(), grb::Error>(())
+    /// ```
+    ///
+    pub fn new(model: Model) -> AsyncModel {
+        assert!(
+            !model.env.is_shared(),
+            "Cannot create async model - environment is used in other models"
+        );
+        AsyncModel(model)
+    }
+
+    /// Optimize the model on another thread.  This method will always trigger a [`Model::update`] on the underlying `Model`.
+    ///
+    /// On success, returns an [`AsyncHandle`] that provides a limited API for model queries.
+    /// The `AsyncModel` can be retrieved by calling [`AsyncHandle::join`](crate::AsyncHandle::join).
+    ///
+    /// # Errors
+    /// An `grb::Error::FromAPI` may occur.  In this case, the `Err` variant contains this error
+    /// and gives back ownership of this `AsyncModel`.
+    ///
+    /// # Examples
+    /// ```
+    /// use grb::prelude::*;
+    /// use grb::AsyncModel;
+    ///
+    /// let mut m = Model::with_env("model", &Env::new("")?)?;
+    /// let x = add_ctsvar!(m, obj: 2)?;
+    /// let y = add_intvar!(m, bounds: 0..100)?;
+    /// m.add_constr("c0", c!(x <= y - 0.5 ))?;
+    /// let m = AsyncModel::new(m);
+    ///
+    /// let handle = match m.optimize() {
+    ///   Err((_, e)) => panic!("{}", e),
+    ///   Ok(h) => h
+    /// };
+    ///
+    /// println!("The model has explored {} MIP nodes so far", handle.node_cnt()?);
+    /// let (m, errors) = handle.join(); // the AsyncModel is always returned
+    /// errors?; // optimisation errors - as if Model::optimize were called.
+    /// let m: Model = m.into(); // get original Model back
+    /// # Ok::<(), grb::Error>(())
+    /// ```
+    pub fn optimize(mut self) -> std::result::Result<AsyncHandle, (Self, Error)> {
+        self.0.mip_start_used = None;
+        match self.0.update().and_then(|()| {
+            self.0
+                .check_apicall(unsafe { ffi::GRBoptimizeasync(self.0.ptr) })
+        }) {
+            Ok(()) => Ok(AsyncHandle(self.0)),
+            Err(e) => Err((self, e)),
+        }
+    }
+}
+
+// TODO: check that multi-objective and scenario optimisation work/are usable
+
+impl std::convert::From<AsyncModel> for Model {
+    fn from(model: AsyncModel) -> Model {
+        model.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    extern crate self as grb;
+
+    #[test]
+    fn set_start_contiguous_and_gaps() -> Result<()> {
+        let mut m = Model::new("")?;
+        let vars = (0..1000)
+            .map(|_| add_ctsvar!(m))
+            .collect::<Result<Vec<_>>>()?;
+        m.update()?;
+
+        // every variable, and a contiguous block
+        m.set_start(vars.iter().map(|&v| (v, 1.0)))?;
+        m.set_start(vars[10..20].iter().map(|&v| (v, 2.0)))?;
+        // gaps and out of order indices
+        m.set_start(vars.iter().step_by(2).map(|&v| (v, 3.0)))?;
+        m.set_start([(vars[5], 4.0), (vars[4], 4.0)])?;
+
+        m.update()?;
+        assert_eq!(
+            m.get_obj_attr_batch(attr::Start, vars[..6].iter().copied())?,
+            [3.0, 1.0, 3.0, 1.0, 4.0, 4.0]
+        );
+        assert_eq!(
+            m.get_obj_attr_batch(attr::Start, vars[10..12].iter().copied())?,
+            [3.0, 2.0]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn model_id_factory() {
+        let mut env = Env::new("").unwrap();
+        env.set(param::OutputFlag, 0).unwrap();
+
+        let mut m1 = Model::with_env("test1", &env).unwrap();
+        let mut m2 = Model::with_env("test2", &env).unwrap();
+
+        let x1 = add_var!(m1, Binary,  name:"x1").unwrap();
+        let x2 = add_var!(m2, Binary,  name:"x2").unwrap();
+        assert_ne!(m1.id, m2.id);
+
+        assert_eq!(x1.model_id, m1.id);
+        assert_eq!(x1.id, 0);
+        assert_eq!(x2.model_id, m2.id);
+        assert_eq!(x2.id, 0);
+    }
+
+    #[test]
+    fn eager_update() {
+        let mut model = Model::new("test").unwrap();
+        assert_eq!(model.get_param(param::UpdateMode).unwrap(), 1);
+        assert!(!model.update_mode_lazy().unwrap());
+        let x = add_binvar!(model, name:"x").unwrap();
+        let y = add_binvar!(model, name:"y").unwrap();
+        let c1 = model.add_constr("c1", c!(x + y <= 1)).unwrap(); // should work fine
+
+        assert_eq!(model.get_attr(attr::NumVars).unwrap(), 0);
+        assert!(model.get_index(&x).is_err());
+        assert_eq!(model.get_index_build(&x).unwrap(), 0);
+        assert!(model.get_index(&y).is_err());
+        assert_eq!(model.get_index_build(&y).unwrap(), 1);
+        assert_eq!(model.get_index_build(&c1).unwrap(), 0);
+
+        model.update().unwrap();
+        assert_eq!(model.get_attr(attr::NumVars).unwrap(), 2);
+        assert_eq!(model.get_index(&x).unwrap(), 0);
+        assert_eq!(model.get_index(&y).unwrap(), 1);
+
+        let z = add_binvar!(model, name:"z").unwrap();
+        assert_eq!(model.get_attr(attr::NumVars).unwrap(), 2);
+        assert_eq!(model.get_index(&x).unwrap(), 0);
+        assert_eq!(model.get_index(&y).unwrap(), 1);
+        assert!(model.get_index(&z).is_err());
+        assert_eq!(model.get_index_build(&z).unwrap(), 2);
+
+        model.remove(y).unwrap();
+        let c2 = model.add_constr("c2", c!(z + y <= 1)).unwrap(); // I know it's weird, because y is removed, but that's what Gurobi does
+        assert_eq!(model.get_index_build(&c2).unwrap(), 1);
+        assert_eq!(model.get_attr(attr::NumVars).unwrap(), 2);
+        assert_eq!(model.get_index(&x).unwrap(), 0);
+        assert_eq!(model.get_index(&y), Err(Error::ModelObjectRemoved)); // No longer available
+        assert!(model.get_index(&z).is_err());
+        assert_eq!(model.get_index_build(&z).unwrap(), 2);
+
+        let w = add_binvar!(model, name:"w").unwrap();
+        assert_eq!(model.get_attr(attr::NumVars).unwrap(), 2);
+        assert_eq!(model.get_index(&x).unwrap(), 0);
+        assert_eq!(model.get_index(&y), Err(Error::ModelObjectRemoved)); // No longer available
+        assert!(model.get_index(&z).is_err());
+        assert_eq!(model.get_index_build(&z).unwrap(), 2);
+        assert!(model.get_index(&w).is_err());
+        assert_eq!(model.get_index_build(&w).unwrap(), 3);
+
+        model.update().unwrap();
+        assert_eq!(model.get_attr(attr::NumVars).unwrap(), 3);
+        assert_eq!(model.get_attr(attr::NumNZs).unwrap(), 2); // start with 2 constraints with 2 vars = 2 x 2 = 4, minus where y appears twice = 4 - 2 = 2
+        assert_eq!(model.get_index(&x).unwrap(), 0);
+        assert!(model.get_index(&y).is_err());
+        assert_eq!(model.get_index(&z).unwrap(), 1);
+        assert_eq!(model.get_index(&w).unwrap(), 2);
+
+        assert_eq!(
+            model.get_obj_attr(attr::VarName, &x).unwrap(),
+            "x".to_string()
+        );
+        assert_eq!(
+            model.get_obj_attr(attr::VarName, &z).unwrap(),
+            "z".to_string()
+        );
+        assert_eq!(
+            model.get_obj_attr(attr::VarName, &w).unwrap(),
+            "w".to_string()
+        );
+    }
+
+    #[test]
+    fn lazy_update() {
+        let mut env = Env::new("").unwrap();
+        env.set(param::OutputFlag, 0).unwrap();
+        env.set(param::UpdateMode, 0).unwrap();
+        let mut model = Model::with_env("bug", &env).unwrap();
+        assert!(model.update_mode_lazy().unwrap());
+
+        let x = add_binvar!(model, name:"x").unwrap();
+        let y = add_binvar!(model, name:"y").unwrap();
+        assert_eq!(model.get_attr(attr::NumVars).unwrap(), 0);
+        assert_eq!(
+            model.get_index_build(&x).unwrap_err(),
+            Error::ModelObjectPending
+        );
+        assert_eq!(
+            model.get_index_build(&y).unwrap_err(),
+            Error::ModelObjectPending
+        );
+        model.add_constr("c1", c!(x + y <= 1)).unwrap_err();
+
+        model.update().unwrap();
+        assert_eq!(model.get_attr(attr::NumVars).unwrap(), 2);
+        assert_eq!(model.get_index(&x).unwrap(), 0);
+        assert_eq!(model.get_index(&y).unwrap(), 1);
+        let c1 = model.add_constr("c1", c!(x + y <= 1)).unwrap();
+
+        model.remove(y).unwrap();
+        let z = add_binvar!(model, name:"z").unwrap();
+        let w = add_binvar!(model, name:"w").unwrap();
+        assert_eq!(model.get_attr(attr::NumVars).unwrap(), 2);
+        assert_eq!(model.get_index(&x).unwrap(), 0);
+        assert_eq!(model.get_index(&y).unwrap_err(), Error::ModelObjectRemoved); // this is updated instantly
+        assert_eq!(
+            model.get_index_build(&z).unwrap_err(),
+            Error::ModelObjectPending
+        );
+        assert_eq!(
+            model.get_index_build(&w).unwrap_err(),
+            Error::ModelObjectPending
+        );
+
+        model.update().unwrap();
+        assert_eq!(model.get_attr(attr::NumVars).unwrap(), 3);
+        assert_eq!(model.get_index(&x).unwrap(), 0);
+        assert_eq!(model.get_index(&y).unwrap_err(), Error::ModelObjectRemoved);
+        assert_eq!(model.get_index(&z).unwrap(), 1);
+        assert_eq!(model.get_index(&w).unwrap(), 2);
+        assert_eq!(model.get_index(&c1).unwrap(), 0);
+
+        assert_eq!(
+            model.get_obj_attr(attr::VarName, &x).unwrap(),
+            "x".to_string()
+        );
+        assert_eq!(
+            model.get_obj_attr(attr::VarName, &z).unwrap(),
+            "z".to_string()
+        );
+        assert_eq!(
+            model.get_obj_attr(attr::VarName, &w).unwrap(),
+            "w".to_string()
+        );
+    }
+
+    #[test]
+    fn num_pending_changes() -> Result<()> {
+        let mut env = Env::new("")?;
+        env.set(param::OutputFlag, 0)?;
+        env.set(param::UpdateMode, 0)?;
+        let mut model = Model::with_env("", &env)?;
+        assert_eq!(model.num_pending_changes(), 0);
+
+        add_binvar!(model)?;
+        add_binvar!(model)?;
+        assert_eq!(model.num_pending_changes(), 2);
+
+        model.update()?;
+        assert_eq!(model.num_pending_changes(), 0);
+        Ok(())
+    }
+
+    #[test]
+    fn lazy_remove_deferred() -> Result<()> {
+        let mut env = Env::new("")?;
+        env.set(param::OutputFlag, 0)?;
+        env.set(param::UpdateMode, 0)?;
+        let mut model = Model::with_env("", &env)?;
+        let vars: Vec<_> = (0..5).map(|_| add_binvar!(model)).collect::<Result<_>>()?;
+        model.update()?;
+
+        for &v in &vars[1..4] {
+            model.remove(v)?;
+        }
+        assert_eq!(model.num_pending_changes(), 3);
+        assert_eq!(model.get_attr(attr::NumVars)?, 5);
+
+        model.update()?;
+        assert_eq!(model.num_pending_changes(), 0);
+        assert_eq!(model.get_attr(attr::NumVars)?, 2);
+        assert_eq!(model.get_index(&vars[0])?, 0);
+        assert_eq!(model.get_index(&vars[4])?, 1);
+        assert_eq!(model.get_index(&vars[2]), Err(Error::ModelObjectRemoved));
+        Ok(())
+    }
+
+    #[test]
+    fn lazy_remove_flush_failure() -> Result<()> {
+        let mut env = Env::new("")?;
+        env.set(param::OutputFlag, 0)?;
+        env.set(param::UpdateMode, 0)?;
+        let mut model = Model::with_env("", &env)?;
+        let x = add_binvar!(model)?;
+        let y = add_binvar!(model)?;
+        model.update()?;
+
+        model.remove(y)?;
+        model.remove(x)?;
+        // delete a variable behind the model's back, so one of the deferred removals is out of range
+        unsafe {
+            assert_eq!(ffi::GRBdelvars(model.ptr, 1, [0].as_ptr()), 0);
+            assert_eq!(ffi::GRBupdatemodel(model.ptr), 0);
+        }
+        assert!(matches!(model.update(), Err(Error::FromAPI(_, _))));
+        // the removals are still queued
+        assert_eq!(model.vars.deferred_removals(), [1, 0]);
+        assert!(matches!(model.update(), Err(Error::FromAPI(_, _))));
+        Ok(())
+    }
+
+    #[test]
+    fn close() -> Result<()> {
+        let mut env = Env::new("")?;
+        env.set(param::OutputFlag, 0)?;
+        env.set(param::UpdateMode, 0)?;
+
+        let mut model = Model::with_env("", &env)?;
+        let x = add_binvar!(model)?;
+        let y = add_binvar!(model)?;
+        model.update()?;
+        model.remove(x)?;
+        model.add_constr("c", c!(y <= 0))?;
+        assert_eq!(model.num_pending_changes(), 2);
+        model.close()?;
+
+        // errors from the final update are propagated
+        let mut model = Model::with_env("", &env)?;
+        let x = add_binvar!(model)?;
+        model.update()?;
+        model.remove(x)?;
+        // delete the variable behind the model's back, so the deferred removal is out of range
+        unsafe {
+            assert_eq!(ffi::GRBdelvars(model.ptr, 1, [0].as_ptr()), 0);
+            assert_eq!(ffi::GRBupdatemodel(model.ptr), 0);
+        }
+        assert!(matches!(model.close(), Err(Error::FromAPI(_, _))));
+        Ok(())
+    }
+
+    #[test]
+    fn reject_nan() -> Result<()> {
+        let mut m = Model::new("")?;
+        let x = add_ctsvar!(m)?;
+        let c = m.add_constr("c", c!(x <= 1))?;
+        m.update()?;
+        let nan = f64::NAN;
+
+        fn is_alg_err<T>(r: Result<T>) -> bool {
+            matches!(r, Err(Error::AlgebraicError(_)))
+        }
+        assert!(is_alg_err(m.add_var("", Continuous, nan, 0.0, 1.0, [])));
+        assert!(is_alg_err(m.add_var(
+            "",
+            Continuous,
+            INFINITY,
+            0.0,
+            1.0,
+            []
+        )));
+        assert!(is_alg_err(m.add_var("", Continuous, 0.0, nan, 1.0, [])));
+        assert!(is_alg_err(m.add_var("", Continuous, 0.0, 0.0, nan, [])));
+        assert!(is_alg_err(m.add_var(
+            "",
+            Continuous,
+            0.0,
+            0.0,
+            1.0,
+            [(c, nan)]
+        )));
+        assert!(is_alg_err(m.add_constr("", c!(x <= nan))));
+        assert!(is_alg_err(m.add_constr("", c!(nan * x <= 1))));
+        assert_eq!(m.num_pending_changes(), 0);
+
+        m.add_var("", Continuous, 0.0, -INFINITY, INFINITY, [])?;
+        Ok(())
+    }
+
+    #[test]
+    fn iter_vars_constrs() -> Result<()> {
+        let mut m = Model::new("")?;
+        let x = add_binvar!(m)?;
+        let y = add_binvar!(m)?;
+        let c = m.add_constr("", c!(x + y <= 1))?;
+        assert_eq!(m.vars().err(), Some(Error::ModelUpdateNeeded));
+        assert_eq!(m.constrs().err(), Some(Error::ModelUpdateNeeded));
+
+        m.update()?;
+        assert_eq!(m.vars()?.collect::<Vec<_>>(), vec![x, y]);
+        assert_eq!(m.constrs()?.collect::<Vec<_>>(), vec![c]);
+        Ok(())
+    }
+
+    #[test]
+    fn objective_sense_from_sign() -> Result<()> {
+        let mut m = Model::new("")?;
+        let x = add_ctsvar!(m)?;
+        m.set_objective(x, -1)?;
+        assert_eq!(m.get_attr(attr::ModelSense)?, Maximize);
+        m.set_objective(x, 1)?;
+        assert_eq!(m.get_attr(attr::ModelSense)?, Minimize);
+        m.set_objective(x, ModelSense::minimize_if(false))?;
+        assert_eq!(m.get_attr(attr::ModelSense)?, Maximize);
+        assert!(matches!(
+            m.set_objective(x, 0),
+            Err(Error::InvalidArgument(_))
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn constr_activity() -> Result<()> {
+        let mut m = Model::new("")?;
+        m.set_param(param::OutputFlag, 0)?;
+        let x = add_ctsvar!(m, bounds: 0..3)?;
+        let y = add_ctsvar!(m, bounds: 0..)?;
+        let c1 = m.add_constr("c1", c!(x + 2 * y <= 10))?;
+        let c2 = m.add_constr("c2", c!(3 * x - y <= 100))?;
+        m.set_objective(x + y, Maximize)?;
+        m.optimize()?;
+
+        for c in [c1, c2] {
+            let rhs = m.get_obj_attr(attr::RHS, &c)?;
+            let slack = m.get_obj_attr(attr::Slack, &c)?;
+            assert!((m.constr_activity(&c)? - (rhs - slack)).abs() < 1e-9);
+        }
+        assert!((m.constr_activity(&c2)? - 5.5).abs() < 1e-9);
+        Ok(())
+    }
+
+    #[test]
+    fn build_scope() -> Result<()> {
+        let mut model = Model::new("")?;
+        assert!(!model.update_mode_lazy()?);
+
+        let vars = model.build_scope(|m| {
+            assert!(m.update_mode_lazy()?);
+            (0..1000)
+                .map(|_| add_binvar!(m))
+                .collect::<Result<Vec<_>>>()
+        })?;
+        assert!(!model.update_mode_lazy()?);
+        assert_eq!(model.num_pending_changes(), 0);
+        assert_eq!(model.get_attr(attr::NumVars)?, 1000);
+        for (i, v) in vars.iter().enumerate() {
+            assert_eq!(model.get_index(v)?, i as i32);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn condition_number() -> Result<()> {
+        let mut m = Model::new("")?;
+        m.set_param(param::OutputFlag, 0)?;
+        m.set_param(param::Method, 0)?;
+        let x = add_ctsvar!(m, bounds: 0..)?;
+        let y = add_ctsvar!(m, bounds: 0..)?;
+        m.add_constr("c1", c!(x + y <= 4))?;
+        m.add_constr("c2", c!(x - y <= 2))?;
+        m.set_objective(x + 2 * y, Maximize)?;
+        assert!(matches!(
+            m.condition_number(false),
+            Err(Error::DataNotAvailable(_))
+        ));
+        m.optimize()?;
+
+        for exact in [false, true] {
+            let kappa = m.condition_number(exact)?;
+            assert!(kappa.is_finite());
+            assert!((1.0..100.0).contains(&kappa));
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn json_solution_string() -> Result<()> {
+        let mut m = Model::new("")?;
+        m.set_param(param::OutputFlag, 0)?;
+        let x = add_ctsvar!(m, name: "x", bounds: 0..1)?;
+        m.set_objective(x, Maximize)?;
+        assert!(m.json_solution_string().is_err());
+        m.optimize()?;
+
+        let json = m.json_solution_string()?;
+        let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+        assert!(value.get("SolutionInfo").is_some());
+        assert!(json.contains("\"SolutionInfo\""));
+        Ok(())
+    }
+
+    #[test]
+    fn sos_size() -> Result<()> {
+        let mut m = Model::new("")?;
+        let x = add_binvar!(m)?;
+        let y = add_binvar!(m)?;
+        m.update()?;
+
+        let is_alg_err = |r: Result<_>| matches!(r, Err(Error::AlgebraicError(_)));
+        assert!(is_alg_err(m.add_sos([], SOSType::Ty1)));
+        assert!(is_alg_err(m.add_sos([], SOSType::Ty2)));
+        assert!(is_alg_err(m.add_sos([(x, 1.0)], SOSType::Ty2)));
+        assert_eq!(m.num_pending_changes(), 0);
+
+        m.add_sos([(x, 1.0)], SOSType::Ty1)?;
+        m.add_sos([(x, 1.0), (y, 2.0)], SOSType::Ty2)?;
+        Ok(())
+    }
+
+    #[test]
+    fn problem_type() -> Result<()> {
+        let mut m = Model::new("")?;
+        let x = add_ctsvar!(m)?;
+        let y = add_ctsvar!(m)?;
+        m.update()?;
+        assert!(!m.is_mip()?);
+        assert!(!m.is_qp()?);
+        assert!(!m.is_qcp()?);
+
+        m.set_objective(x * x + y, Minimize)?;
+        assert!(m.is_qp()?);
+        m.add_qconstr("q", c!(x * y <= 1))?;
+        add_binvar!(m)?;
+        m.update()?;
+        assert!(m.is_mip()?);
+        assert!(m.is_qcp()?);
+        Ok(())
+    }
+
+    #[test]
+    fn get_iis() -> Result<()> {
+        let mut m = Model::new("")?;
+        m.set_param(param::OutputFlag, 0)?;
+        let x = add_ctsvar!(m, bounds: 0..10)?;
+        let y = add_ctsvar!(m, bounds: 0..10)?;
+        let z = add_ctsvar!(m, bounds: 0..10)?;
+        let c1 = m.add_constr("c1", c!(x + y >= 5))?;
+        let _c2 = m.add_constr("c2", c!(z <= 3))?;
+        let c3 = m.add_constr("c3", c!(x + y <= 2))?;
+        m.add_qconstr("q", c!(z * z <= 100))?;
+        m.update()?;
+        m.compute_iis()?;
+
+        let iis = m.get_iis()?;
+        assert_eq!(iis.constrs, [c1, c3]);
+        assert!(iis.qconstrs.is_empty());
+        assert!(iis.sos.is_empty());
+        assert!(iis.genconstrs.is_empty());
+        assert!(iis.lb_vars.is_empty());
+        assert!(iis.ub_vars.is_empty());
+
+        let mut m = Model::new("")?;
+        m.set_param(param::OutputFlag, 0)?;
+        let x = add_ctsvar!(m, bounds: 0..1)?;
+        let y = add_ctsvar!(m, bounds: 2..3)?;
+        let c = m.add_constr("c", c!(x - y == 0))?;
+        m.update()?;
+        m.compute_iis()?;
+        let iis = m.get_iis()?;
+        assert_eq!(iis.constrs, [c]);
+        assert_eq!(iis.ub_vars, [x]);
+        assert_eq!(iis.lb_vars, [y]);
+        Ok(())
+    }
+
+    #[test]
+    fn compute_iis_with_forces() -> Result<()> {
+        let mut m = Model::new("")?;
+        m.set_param(param::OutputFlag, 0)?;
+        let x = add_ctsvar!(m, bounds: ..)?;
+        let c1 = m.add_constr("c1", c!(x <= 1))?;
+        let c2 = m.add_constr("c2", c!(x >= 2))?;
+        let c3 = m.add_constr("c3", c!(x >= 3))?;
+        m.optimize()?;
+
+        m.compute_iis_with_forces([(c2, IisForce::Exclude)])?;
+        let in_iis = m.get_obj_attr_batch(attr::IISConstr, [c1, c2, c3])?;
+        assert_eq!(in_iis, vec![1, 0, 1]);
+        Ok(())
+    }
+
+    #[test]
+    fn runtime_and_work() -> Result<()> {
+        let mut m = Model::new("")?;
+        m.set_param(param::OutputFlag, 0)?;
+        let x = add_intvar!(m, bounds: 0..10)?;
+        m.set_objective(x, Maximize)?;
+        m.optimize()?;
+        assert!(m.runtime()? >= 0.0);
+        assert!(m.work()? >= 0.0);
+        Ok(())
+    }
+
+    #[test]
+    fn objective_builder() -> Result<()> {
+        let mut m = Model::new("")?;
+        m.set_param(param::OutputFlag, 0)?;
+        let x = add_ctsvar!(m, bounds: 0..1)?;
+        let y = add_ctsvar!(m, bounds: 0..2)?;
+        let z = add_intvar!(m, bounds: 0..3)?;
+
+        let mut obj = m.objective_builder();
+        obj += 2 * x;
+        obj += y - z;
+        obj += 10;
+        obj.set(Minimize)?;
+        m.optimize()?;
+        assert_eq!(m.get_attr(attr::ObjVal)?, 7.0);
+        Ok(())
+    }
+
+    #[test]
+    fn add_linear_constr() -> Result<()> {
+        let mut m = Model::new("")?;
+        let x = add_ctsvar!(m)?;
+        let y = add_ctsvar!(m)?;
+        let c1 = m.add_linear_constr("c1", vec![(x, 2.0), (y, 3.0)], ConstrSense::Less, 5.0)?;
+        let c2 = m.add_constr("c2", c!(2 * x + 3 * y <= 5))?;
+        m.update()?;
+
+        let sorted_row = |c: &Constr| -> Result<Vec<(i32, f64)>> {
+            let (inds, vals) = m.get_constr_coeffs(c)?;
+            let mut row: Vec<_> = inds.into_iter().zip(vals).collect();
+            row.sort_by_key(|&(i, _)| i);
+            Ok(row)
+        };
+        assert_eq!(sorted_row(&c1)?, vec![(0, 2.0), (1, 3.0)]);
+        assert_eq!(sorted_row(&c1)?, sorted_row(&c2)?);
+        assert_eq!(
+            m.get_obj_attr(attr::RHS, &c1)?,
+            m.get_obj_attr(attr::RHS, &c2)?
+        );
+        assert!(matches!(
+            m.get_obj_attr(attr::Sense, &c1)?,
+            ConstrSense::Less
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn objective_introspection() -> Result<()> {
+        let mut m = Model::new("")?;
+        let x = add_ctsvar!(m)?;
+        let y = add_ctsvar!(m)?;
+        add_ctsvar!(m)?;
+
+        m.set_objective(x * x + 2 * y, Minimize)?;
+        assert!(m.objective_has_quad()?);
+        assert_eq!(m.objective_num_linear_terms()?, 1);
+
+        m.set_objective(x + y + 1, Minimize)?;
+        assert!(!m.objective_has_quad()?);
+        assert_eq!(m.objective_num_linear_terms()?, 2);
+        Ok(())
+    }
+
+    #[test]
+    fn set_coeffs_nonzeros() -> Result<()> {
+        let mut m = Model::new("")?;
+        let x = add_ctsvar!(m)?;
+        let y = add_ctsvar!(m)?;
+        let z = add_ctsvar!(m)?;
+        let c = m.add_constr("c", c!(x + y <= 1))?;
+        m.update()?;
+        assert_eq!(m.get_attr(attr::NumNZs)?, 2);
+
+        // add
+        m.set_coeff(&z, &c, 3.0)?;
+        m.update()?;
+        assert_eq!(m.get_attr(attr::NumNZs)?, 3);
+        assert_eq!(m.get_coeff(&z, &c)?, 3.0);
+
+        // modify
+        m.set_coeffs([(x, c, 2.0)])?;
+        m.update()?;
+        assert_eq!(m.get_attr(attr::NumNZs)?, 3);
+        assert_eq!(m.get_coeff(&x, &c)?, 2.0);
+
+        // remove
+        m.set_coeffs([(y, c, 0.0)])?;
+        m.update()?;
+        assert_eq!(m.get_attr(attr::NumNZs)?, 2);
+        assert_eq!(m.get_coeff(&y, &c)?, 0.0);
+
+        m.set_coeff(&z, &c, 0.0)?;
+        m.update()?;
+        assert_eq!(m.get_attr(attr::NumNZs)?, 1);
+        assert_eq!(m.get_coeff(&z, &c)?, 0.0);
+
+        // zero for a missing coefficient does not add an entry
+        m.set_coeffs([(y, c, 0.0), (z, c, 0.0)])?;
+        m.update()?;
+        assert_eq!(m.get_attr(attr::NumNZs)?, 1);
+        Ok(())
+    }
+
+    #[test]
+    fn add_genconstr_indicator_range() -> Result<()> {
+        let mut m = Model::new("")?;
+        m.set_param(param::OutputFlag, 0)?;
+        let b = add_binvar!(m)?;
+        let x = add_ctsvar!(m, bounds: 0..10)?;
+        let y = add_ctsvar!(m, bounds: 0..10)?;
+        m.add_genconstr_indicator_range("r", b, true, c!(x + y in 0..5))?;
+        m.update()?;
+        assert_eq!(m.get_attr(attr::NumGenConstrs)?, 2);
+        assert_eq!(m.get_genconstrs()?.len(), 2);
+
+        m.add_constr("fix", c!(b == 1))?;
+        m.set_objective(x + y, Maximize)?;
+        m.optimize()?;
+        assert_eq!(m.get_attr(attr::ObjVal)?, 5.0);
+
+        assert!(matches!(
+            m.add_genconstr_indicator_range("q", b, true, c!(x * y in 0..5)),
+            Err(Error::AlgebraicError(_))
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn add_genconstr_indicators() -> Result<()> {
+        let mut m = Model::new("")?;
+        m.set_param(param::OutputFlag, 0)?;
+        let x = add_ctsvar!(m, bounds: 0..10)?;
+        let y = add_ctsvar!(m, bounds: 0..10)?;
+        let b = add_binvar!(m)?;
+        let c = add_binvar!(m)?;
+        let gcs = m.add_genconstr_indicators(vec![
+            ("i1".to_string(), b, true, c!(x + y <= 4)),
+            ("i2".to_string(), c, false, c!(x >= 1)),
+            ("i3".to_string(), b, false, c!(y == 2)),
+        ])?;
+        m.update()?;
+        assert_eq!(m.get_attr(attr::NumGenConstrs)?, 3);
+        let inds = gcs
+            .iter()
+            .map(|gc| m.get_index(gc))
+            .collect::<Result<Vec<_>>>()?;
+        assert_eq!(inds, [0, 1, 2]);
+        assert_eq!(
+            m.get_obj_attr_batch(attr::GenConstrName, gcs.iter().copied())?,
+            ["i1", "i2", "i3"]
+        );
+
+        let (ind, val, _) = m.get_genconstr_indicator(&gcs[1])?;
+        assert_eq!((ind, val), (c, false));
+
+        m.add_constr("fix_b", c!(b == 1))?;
+        m.add_constr("fix_c", c!(c == 0))?;
+        m.set_objective(x + y, Maximize)?;
+        m.optimize()?;
+        assert_eq!(m.get_attr(attr::ObjVal)?, 4.0);
+        assert!(m.get_obj_attr(attr::X, &x)? >= 1.0 - 1e-6);
+
+        assert!(matches!(
+            m.add_genconstr_indicators(vec![("q".to_string(), b, true, c!(x * y <= 1))]),
+            Err(Error::AlgebraicError(_))
+        ));
+        m.update()?;
+        assert_eq!(m.get_attr(attr::NumGenConstrs)?, 3);
+        Ok(())
+    }
+
+    #[test]
+    fn write_subset() -> Result<()> {
+        let mut m = Model::new("")?;
+        let x = add_ctsvar!(m, name: "x")?;
+        let y = add_ctsvar!(m, name: "y")?;
+        m.add_constr("c1", c!(x + y <= 1))?;
+        let c2 = m.add_constr("c2", c!(x - y <= 1))?;
+        m.add_qconstr("q", c!(x * y <= 1))?;
+        m.set_objective(x + 2 * y, Maximize)?;
+
+        let path = std::env::temp_dir().join("grb_test_write_subset.lp");
+        let read_back = |spec| -> Result<Model> {
+            m.write_subset(&path, spec)?;
+            Model::from_file(&path)
+        };
+
+        let obj_only = read_back(SubsetSpec::ObjectiveOnly)?;
+        assert_eq!(obj_only.get_attr(attr::NumVars)?, 2);
+        assert_eq!(obj_only.get_attr(attr::NumConstrs)?, 0);
+        assert_eq!(obj_only.get_attr(attr::NumQConstrs)?, 0);
+        assert_eq!(obj_only.get_objective()?.0.into_linexpr()?.num_terms(), 2);
+
+        let constrs = read_back(SubsetSpec::Constrs(vec![c2]))?;
+        assert_eq!(constrs.get_attr(attr::NumConstrs)?, 1);
+        assert_eq!(constrs.get_attr(attr::NumQConstrs)?, 0);
+        assert!(constrs.get_constr_by_name("c2")?.is_some());
+
+        let vars = read_back(SubsetSpec::Vars(vec![y]))?;
+        assert_eq!(vars.get_attr(attr::NumVars)?, 1);
+        assert!(vars.get_var_by_name("y")?.is_some());
+
+        // the original model is untouched
+        assert_eq!(m.get_attr(attr::NumConstrs)?, 2);
+        std::fs::remove_file(&path).ok();
+        Ok(())
+    }
+
+    #[test]
+    fn lp_relaxation_bound() -> Result<()> {
+        let mut m = Model::new("")?;
+        m.set_param(param::OutputFlag, 0)?;
+        let x = add_intvar!(m, bounds: 0..10)?;
+        let y = add_binvar!(m)?;
+        m.add_constr("c1", c!(3 * x + 2 * y <= 8))?;
+        m.add_constr("c2", c!(x - y <= 1.5))?;
+        m.set_objective(2 * x + 3 * y, Maximize)?;
+        let bound = m.lp_relaxation_bound()?;
+
+        let mut relaxed = m.try_clone()?;
+        let vars = relaxed.get_vars()?.to_vec();
+        relaxed.set_obj_attr_batch(attr::VType, vars.into_iter().map(|v| (v, Continuous)))?;
+        relaxed.optimize()?;
+        assert!((relaxed.get_attr(attr::ObjVal)? - bound).abs() < 1e-6);
+        assert!(!relaxed.is_mip()?);
+
+        // the original is still a MIP
+        assert!(m.is_mip()?);
+        m.optimize()?;
+        assert!(m.get_attr(attr::ObjVal)? <= bound + 1e-6);
+
+        m.add_constr("infeasible", c!(x + y >= 20))?;
+        assert!(matches!(
+            m.lp_relaxation_bound(),
+            Err(Error::DataNotAvailable(_))
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn add_var_start() -> Result<()> {
+        let mut m = Model::new("")?;
+        let x = add_ctsvar!(m, name: "x", bounds: 0..1, start: 0.5)?;
+        let y = add_intvar!(m, start: 3)?;
+        let z = add_binvar!(m)?;
+        m.update()?;
+        assert_eq!(m.get_obj_attr(attr::Start, &x)?, 0.5);
+        assert_eq!(m.get_obj_attr(attr::Start, &y)?, 3.0);
+        assert_eq!(
+            m.get_obj_attr(attr::Start, &z)?,
+            crate::constants::GRB_UNDEFINED
+        );
+
+        assert!(matches!(
+            add_ctsvar!(m, bounds: 0..1, start: 2),
+            Err(Error::AlgebraicError(_))
+        ));
+        assert_eq!(m.num_pending_changes(), 0);
+        Ok(())
+    }
+
+    #[test]
+    fn add_var_start_lazy() -> Result<()> {
+        let mut env = Env::new("")?;
+        env.set(param::OutputFlag, 0)?;
+        env.set(param::UpdateMode, 0)?;
+        let mut m = Model::with_env("", &env)?;
+        let x = add_ctsvar!(m, bounds: 0..1, start: 0.5)?;
+        let y = add_intvar!(m, start: 3)?;
+        assert_eq!(m.num_pending_changes(), 2);
+        m.update()?;
+        assert_eq!(m.get_attr(attr::NumVars)?, 2);
+        assert_eq!(m.get_obj_attr(attr::Start, &x)?, 0.5);
+        assert_eq!(m.get_obj_attr(attr::Start, &y)?, 3.0);
+
+        assert!(matches!(
+            add_ctsvar!(m, bounds: 0..1, start: 2),
+            Err(Error::InvalidArgument(_))
+        ));
+        assert_eq!(m.num_pending_changes(), 0);
+        m.update()?;
+        assert_eq!(m.get_attr(attr::NumVars)?, 2);
+        Ok(())
+    }
+
+    #[test]
+    fn add_var_start_model_ref() -> Result<()> {
+        fn add(m: &mut Model) -> Result<Var> {
+            add_intvar!(m, bounds: 0..10, start: 4)
+        }
+        let mut m = Model::new("")?;
+        let x = add(&mut m)?;
+        m.update()?;
+        assert_eq!(m.get_obj_attr(attr::Start, &x)?, 4.0);
+        Ok(())
+    }
+
+    #[test]
+    fn add_constrs_map() -> Result<()> {
+        let mut m = Model::new("")?;
+        let x = add_ctsvar!(m)?;
+        let y = add_ctsvar!(m)?;
+        let constrs = m.add_constrs_map(vec![
+            ("a".to_string(), c!(x <= 1)),
+            ("b".to_string(), c!(y <= 2)),
+            ("c".to_string(), c!(x + y <= 3)),
+        ])?;
+        m.update()?;
+        assert_eq!(constrs.len(), 3);
+
+        let b = constrs["b"];
+        assert_eq!(m.get_obj_attr(attr::ConstrName, &b)?, "b");
+        assert_eq!(m.get_obj_attr(attr::RHS, &b)?, 2.0);
+        assert_eq!(m.get_constr_by_name("b")?, Some(b));
+        Ok(())
+    }
+
+    #[test]
+    fn set_objective_parts() -> Result<()> {
+        let mut m = Model::new("")?;
+        m.set_param(param::OutputFlag, 0)?;
+        let x = add_ctsvar!(m, bounds: -10..10)?;
+        let y = add_ctsvar!(m, bounds: -10..10)?;
+        let z = add_ctsvar!(m, bounds: 0..5)?;
+        m.add_constr("c", c!(x + y + z >= 1))?;
+
+        m.set_objective(0.5 * (x * x) + 0.5 * (y * y) - x + 2 * y + z + 3, Minimize)?;
+        m.optimize()?;
+        let merged = m.get_attr(attr::ObjVal)?;
+
+        let mut quad = QuadExpr::new();
+        quad.add_qterm(0.5, x, x).add_qterm(0.5, y, y);
+        quad.add_term(1.0, z);
+        let mut linear = LinExpr::new();
+        linear.add_term(-1.0, x).add_term(2.0, y);
+        m.set_objective_parts(quad, linear, 3.0, Minimize)?;
+        m.optimize()?;
+        assert!((m.get_attr(attr::ObjVal)? - merged).abs() < 1e-6);
+
+        let (obj, _) = m.get_objective()?;
+        let obj = obj.into_quadexpr();
+        assert_eq!(obj.num_qterms(), 2);
+        assert_eq!(obj.num_terms(), 3);
+        assert_eq!(obj.get_offset(), 3.0);
+
+        m.set_objective_parts(QuadExpr::new(), LinExpr::new(), 0.0, -1)?;
+        assert_eq!(m.get_attr(attr::ModelSense)?, Maximize);
+        assert!(matches!(
+            m.set_objective_parts(QuadExpr::new(), LinExpr::new(), 0.0, 0),
+            Err(Error::InvalidArgument(_))
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn add_free_var() -> Result<()> {
+        let mut m = Model::new("")?;
+        let x = m.add_free_var("x", Continuous, 1.0)?;
+        m.update()?;
+        assert_eq!(m.get_obj_attr(attr::LB, &x)?, -INFINITY);
+        assert_eq!(m.get_obj_attr(attr::LB, &x)?, NEG_INFINITY);
+        assert_eq!(m.get_obj_attr(attr::UB, &x)?, INFINITY);
+        assert_eq!(m.get_obj_attr(attr::Obj, &x)?, 1.0);
+        Ok(())
+    }
+
+    #[test]
+    fn object_counts() -> Result<()> {
+        let mut model = Model::new("model1")?;
+        let x1 = add_ctsvar!(model, name: "x1", bounds: ..)?;
+        let x2 = add_intvar!(model, name: "x2", bounds: ..)?;
+        model.add_constr("c0", c!(x1 + 2 * x2 >= -14))?;
+        model.add_constr("c1", c!(-4 * x1 - x2 <= -33))?;
+        model.add_constr("c2", c!(2 * x1 <= 20 - x2))?;
+        model.update()?;
+
+        assert_eq!(model.num_vars()?, 2);
+        assert_eq!(model.num_constrs()?, 3);
+        assert_eq!(model.num_qconstrs()?, 0);
+        assert_eq!(model.num_genconstrs()?, 0);
+        assert_eq!(model.num_sos()?, 0);
+
+        model.add_qconstr("q0", c!(x1 * x1 <= 100))?;
+        model.add_genconstr_max("g0", x1, vec![x2], None)?;
+        model.update()?;
+        assert_eq!(model.num_qconstrs()?, 1);
+        assert_eq!(model.num_genconstrs()?, 1);
+        Ok(())
+    }
+
+    #[test]
+    fn verify_solution() -> Result<()> {
+        let mut m = Model::new("")?;
+        m.set_param(param::OutputFlag, 0)?;
+        let x = add_ctsvar!(m, bounds: 0..3)?;
+        let y = add_ctsvar!(m, bounds: 0..)?;
+        m.add_constr("c1", c!(x + 2 * y <= 10))?;
+        m.add_constr("c2", c!(3 * x - y >= -100))?;
+        m.add_constr("c3", c!(x - y == -1))?;
+        m.set_objective(x + y, Maximize)?;
+        m.optimize()?;
+        assert_eq!(m.status()?, Status::Optimal);
+        assert!(m.verify_solution(1e-6)?.is_empty());
+
+        // the activity 0.1 + 0.2 is not exactly 0.3 in floating point
+        let mut m = Model::new("")?;
+        m.set_param(param::OutputFlag, 0)?;
+        let x = add_ctsvar!(m, bounds: 1..1)?;
+        let y = add_ctsvar!(m, bounds: 1..1)?;
+        let c = m.add_constr("c", c!(0.1 * x + 0.2 * y <= 0.3))?;
+        m.optimize()?;
+        assert_eq!(m.status()?, Status::Optimal);
+        assert!(m.verify_solution(1e-9)?.is_empty());
+        let violations = m.verify_solution(0.0)?;
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].constr, c);
+        assert!(matches!(violations[0].sense, ConstrSense::Less));
+        assert!(violations[0].amount > 0.0 && violations[0].amount < 1e-12);
+        Ok(())
+    }
+
+    #[test]
+    fn solve_stats() -> Result<()> {
+        let mut m = Model::new("")?;
+        m.set_param(param::OutputFlag, 0)?;
+        let x = add_intvar!(m, bounds: 0..10)?;
+        let y = add_intvar!(m, bounds: 0..10)?;
+        m.add_constr("c", c!(2 * x + 3 * y <= 17))?;
+        m.set_objective(x + 2 * y, Maximize)?;
+        assert!(m.solve_stats().is_err());
+
+        m.optimize()?;
+        let stats = m.solve_stats()?;
+        assert!(stats.node_count >= 0.0);
+        assert!(stats.iter_count >= 0.0);
+        assert!(stats.sol_count >= 1);
+        Ok(())
+    }
+
+    #[test]
+    fn rename_in_bulk() -> Result<()> {
+        let mut m = Model::new("")?;
+        let vars = (0..5)
+            .map(|i| add_ctsvar!(m, name: &format!("x{i}")))
+            .collect::<Result<Vec<_>>>()?;
+        let c = m.add_constr("c", c!(vars.iter().grb_sum() <= 1))?;
+        m.update()?;
+
+        m.rename_vars(|name| format!("a_{name}"))?;
+        m.rename_constrs(|name| name.to_uppercase())?;
+        m.update()?;
+        assert_eq!(
+            m.get_obj_attr_batch(attr::VarName, vars.iter().copied())?,
+            ["a_x0", "a_x1", "a_x2", "a_x3", "a_x4"]
+        );
+        assert_eq!(m.get_name(&c)?, "C");
+
+        add_ctsvar!(m)?;
+        assert_eq!(
+            m.rename_vars(|name| name.to_string()),
+            Err(Error::ModelUpdateNeeded)
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn optimize_capturing_log() -> Result<()> {
+        let mut m = Model::new("")?;
+        let x = add_intvar!(m, bounds: 0..10)?;
+        let y = add_intvar!(m, bounds: 0..10)?;
+        m.add_constr("c", c!(2 * x + 3 * y <= 17))?;
+        m.set_objective(x + 2 * y, Maximize)?;
+
+        let (status, log) = m.optimize_capturing_log()?;
+        assert_eq!(status, Status::Optimal);
+        assert!(log.contains("Optimal solution found"), "{}", log);
+        assert_eq!(m.get_param(param::LogToConsole)?, 1);
+        Ok(())
+    }
+
+    #[test]
+    fn add_sos1_sos2() -> Result<()> {
+        let mut m = Model::new("")?;
+        let x = add_ctsvar!(m)?;
+        let y = add_ctsvar!(m)?;
+        let z = add_ctsvar!(m)?;
+        m.update()?;
+        let s1 = m.add_sos1(vec![(x, 1.0), (y, 2.0)])?;
+        let s2 = m.add_sos2(vec![(x, 1.0), (y, 2.0), (z, 3.0)])?;
+        m.update()?;
+
+        let sos_type = |sos: &SOS| -> Result<(c_int, c_int)> {
+            let idx = m.get_index(sos)?;
+            let (mut nummembers, mut sostype, mut beg) = (0, 0, 0);
+            let mut ind = [0; 3];
+            let mut weight = [0.0; 3];
+            m.check_apicall(unsafe {
+                ffi::GRBgetsos(
+                    m.ptr,
+                    &mut nummembers,
+                    &mut sostype,
+                    &mut beg,
+                    ind.as_mut_ptr(),
+                    weight.as_mut_ptr(),
+                    idx,
+                    1,
+                )
+            })?;
+            Ok((sostype, nummembers))
+        };
+        assert_eq!(sos_type(&s1)?, (SOSType::Ty1 as c_int, 2));
+        assert_eq!(sos_type(&s2)?, (SOSType::Ty2 as c_int, 3));
+        Ok(())
+    }
+
+    #[test]
+    fn multi_objective() -> Result<()> {
+        let mut m = Model::new("")?;
+        m.set_param(param::OutputFlag, 0)?;
+        let x = add_ctsvar!(m, bounds: 0..1)?;
+        let y = add_ctsvar!(m, bounds: 0..1)?;
+        m.add_constr("c", c!(x + y <= 1))?;
+        m.set_objective(0, Maximize)?;
+        m.set_objective_n(0, x, 2, 1.0, "first")?;
+        m.set_objective_n(1, y + 3, 1, 1.0, "second")?;
+        assert_eq!(m.get_attr(attr::NumObj)?, 2);
+
+        let obj = m.get_objective_n(1)?;
+        assert_eq!(obj.num_terms(), 1);
+        assert_eq!(obj.get_offset(), 3.0);
+        m.set_param(param::ObjNumber, 0)?;
+        assert_eq!(m.get_attr(attr::ObjNName)?, "first");
+        assert_eq!(m.get_attr(attr::ObjNPriority)?, 2);
+        assert!(matches!(
+            m.get_objective_n(2),
+            Err(Error::InvalidArgument(_))
+        ));
+        assert!(matches!(
+            m.set_objective_n(2, x * y, 0, 1.0, "quad"),
+            Err(Error::AlgebraicError(_))
+        ));
+
+        // x has higher priority, so it is maximised first
+        m.optimize()?;
+        assert_eq!(m.status()?, Status::Optimal);
+        assert_eq!(m.get_obj_attr(attr::X, &x)?, 1.0);
+        assert_eq!(m.get_obj_attr(attr::X, &y)?, 0.0);
+        Ok(())
+    }
+
+    #[test]
+    fn objective_n_tolerances() -> Result<()> {
+        let mut m = Model::new("")?;
+        m.set_param(param::OutputFlag, 0)?;
+        let x = add_intvar!(m, bounds: 0..10)?;
+        let y = add_intvar!(m, bounds: 0..10)?;
+        m.add_constr("c", c!(x + y <= 10))?;
+        m.set_objective(0, Maximize)?;
+        m.set_objective_n(0, x, 2, 1.0, "first")?;
+        m.set_objective_n(1, y, 1, 1.0, "second")?;
+        m.set_objective_n_tolerances(0, 3.0, 0.0)?;
+        m.set_objective_n_tolerances(1, 0.5, 0.25)?;
+        assert!(matches!(
+            m.set_objective_n_tolerances(2, 0.0, 0.0),
+            Err(Error::InvalidArgument(_))
+        ));
+        m.update()?;
+
+        m.set_param(param::ObjNumber, 0)?;
+        assert_eq!(m.get_attr(attr::ObjNAbsTol)?, 3.0);
+        assert_eq!(m.get_attr(attr::ObjNRelTol)?, 0.0);
+        m.set_param(param::ObjNumber, 1)?;
+        assert_eq!(m.get_attr(attr::ObjNAbsTol)?, 0.5);
+        assert_eq!(m.get_attr(attr::ObjNRelTol)?, 0.25);
+
+        // x may be degraded by up to 3 from its optimal value of 10 in favour of y
+        m.optimize()?;
+        assert_eq!(m.get_obj_attr(attr::X, &x)?.round(), 7.0);
+        assert_eq!(m.get_obj_attr(attr::X, &y)?.round(), 3.0);
+        Ok(())
+    }
+
+    #[test]
+    fn start_was_used() -> Result<()> {
+        let mut m = Model::new("")?;
+        let x = add_intvar!(m, bounds: 0..10)?;
+        let y = add_intvar!(m, bounds: 0..10)?;
+        m.add_constr("c", c!(2 * x + 3 * y <= 17))?;
+        m.set_objective(x + 2 * y, Maximize)?;
+        assert_eq!(m.start_was_used()?, None);
+
+        // an optimal start
+        m.set_obj_attr_batch(attr::Start, vec![(x, 1.0), (y, 5.0)])?;
+        let (status, _) = m.optimize_capturing_log()?;
+        assert_eq!(status, Status::Optimal);
+        assert_eq!(m.start_was_used()?, Some(true));
+
+        m.reset(1)?;
+        m.set_param(param::OutputFlag, 0)?;
+        m.optimize()?;
+        assert_eq!(m.start_was_used()?, None);
+
+        assert_eq!(
+            parse_mip_start_used("User MIP start did not produce a new incumbent solution"),
+            Some(false)
+        );
+        assert_eq!(parse_mip_start_used("Optimal solution found"), None);
+        Ok(())
+    }
+
+    #[test]
+    fn get_linear_constr_expr() -> Result<()> {
+        let mut m = Model::new("")?;
+        let x = add_ctsvar!(m)?;
+        let y = add_ctsvar!(m)?;
+        let z = add_ctsvar!(m)?;
+        let c1 = m.add_constr("c1", c!(x + 2 * y - 3 * z <= 4 + x + y))?;
+        let c2 = m.add_constr("c2", c!(z == 1))?;
+        assert_eq!(
+            m.get_linear_constr_expr(&c1).err(),
+            Some(Error::ModelUpdateNeeded)
+        );
+        m.update()?;
+
+        let (lhs, sense, rhs) = m.get_linear_constr_expr(&c1)?;
+        let mut terms: Vec<_> = lhs.iter_terms_sorted().map(|(&v, &a)| (v, a)).collect();
+        terms.retain(|&(_, a)| a != 0.0);
+        assert_eq!(terms, [(y, 1.0), (z, -3.0)]);
+        assert!(matches!(sense, ConstrSense::Less));
+        assert_eq!(rhs, 4.0);
+
+        let (lhs, sense, rhs) = m.get_linear_constr_expr(&c2)?;
+        assert_eq!(lhs.iter_terms_sorted().collect::<Vec<_>>(), [(&z, &1.0)]);
+        assert!(matches!(sense, ConstrSense::Equal));
+        assert_eq!(rhs, 1.0);
+
+        m.remove(c2)?;
+        m.update()?;
+        assert_eq!(
+            m.get_linear_constr_expr(&c2).err(),
+            Some(Error::ModelObjectRemoved)
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn get_sos_constr() -> Result<()> {
+        let mut m = Model::new("")?;
+        let x = add_ctsvar!(m)?;
+        let y = add_ctsvar!(m)?;
+        let z = add_ctsvar!(m)?;
+        m.update()?;
+        let s1 = m.add_sos(vec![(z, 3.0), (x, 1.0)], SOSType::Ty1)?;
+        let s2 = m.add_sos(vec![(x, 1.0), (y, 2.0), (z, 3.0)], SOSType::Ty2)?;
+        assert_eq!(m.get_sos_constr(&s1).err(), Some(Error::ModelUpdateNeeded));
+        m.update()?;
+
+        let (ty, mut members) = m.get_sos_constr(&s1)?;
+        assert!(matches!(ty, SOSType::Ty1));
+        members.sort_by(|a, b| a.1.partial_cmp(&b.1).unwrap());
+        assert_eq!(members, [(x, 1.0), (z, 3.0)]);
+
+        let (ty, members) = m.get_sos_constr(&s2)?;
+        assert!(matches!(ty, SOSType::Ty2));
+        assert_eq!(members.len(), 3);
+        Ok(())
+    }
+
+    #[test]
+    fn add_sos_batch() -> Result<()> {
+        let mut m = Model::new("")?;
+        let vars = (0..4).map(|_| add_ctsvar!(m)).collect::<Result<Vec<_>>>()?;
+        m.update()?;
+        assert!(m.add_sos_batch(vec![])?.is_empty());
+
+        let sets = vec![
+            (vec![(vars[0], 1.0), (vars[1], 2.0)], SOSType::Ty1),
+            (
+                vec![(vars[1], 1.0), (vars[2], 2.0), (vars[3], 3.0)],
+                SOSType::Ty2,
+            ),
+            (vec![(vars[3], 1.0)], SOSType::Ty1),
+        ];
+        let sos = m.add_sos_batch(sets)?;
+        assert_eq!(sos.len(), 3);
+        assert_eq!(m.num_pending_changes(), 3);
+        m.update()?;
+        assert_eq!(m.num_sos()?, 3);
+        assert_eq!(m.get_sos()?, sos.as_slice());
+
+        let (ty, members) = m.get_sos_constr(&sos[1])?;
+        assert!(matches!(ty, SOSType::Ty2));
+        assert_eq!(members.len(), 3);
+        let (ty, members) = m.get_sos_constr(&sos[2])?;
+        assert!(matches!(ty, SOSType::Ty1));
+        assert_eq!(members, [(vars[3], 1.0)]);
+
+        // sizes are checked before anything is added
+        let sets = vec![
+            (vec![(vars[0], 1.0), (vars[1], 2.0)], SOSType::Ty2),
+            (vec![(vars[0], 1.0)], SOSType::Ty2),
+        ];
+        assert!(matches!(
+            m.add_sos_batch(sets),
+            Err(Error::AlgebraicError(_))
+        ));
+        assert_eq!(m.num_pending_changes(), 0);
+        Ok(())
+    }
+
+    #[test]
+    fn export_report() -> Result<()> {
+        let mut m = Model::new("")?;
+        m.set_param(param::OutputFlag, 0)?;
+        let x = add_ctsvar!(m, bounds: 0..3, obj: 1)?;
+        let y = add_ctsvar!(m, bounds: 0.., obj: 1)?;
+        let c1 = m.add_constr("c1", c!(x + 2 * y <= 10))?;
+        let c2 = m.add_constr("c2", c!(x - y >= -100))?;
+        m.set_attr(attr::ModelSense, Maximize)?;
+        assert!(m.export_report().is_err());
+        m.optimize()?;
+        assert_eq!(m.status()?, Status::Optimal);
+
+        let r = m.export_report()?;
+        assert_eq!(r.vars, [x, y]);
+        assert_eq!(r.constrs, [c1, c2]);
+        for v in [&r.x, &r.reduced_costs].iter() {
+            assert_eq!(v.len(), 2);
+        }
+        for v in [&r.duals, &r.slacks].iter() {
+            assert_eq!(v.len(), 2);
+        }
+        assert_eq!(r.obj_ranges.len(), 2);
+        assert_eq!(r.rhs_ranges.len(), 2);
+        assert_eq!(r.x, [3.0, 3.5]);
+        assert!((r.obj_val - (r.x[0] + r.x[1])).abs() < 1e-9);
+        // strong duality: the objective equals the dual objective
+        let dual_obj = 10.0 * r.duals[0] - 100.0 * r.duals[1] + 3.0 * r.reduced_costs[0];
+        assert!((r.obj_val - dual_obj).abs() < 1e-6);
+        // basic variables have no reduced cost, basic constraints have no dual value
+        for (&b, &rc) in r.vbasis.iter().zip(&r.reduced_costs) {
+            if b == 0 {
+                assert!(rc.abs() < 1e-9);
+            }
+        }
+        for (&b, &pi) in r.cbasis.iter().zip(&r.duals) {
+            if b == 0 {
+                assert!(pi.abs() < 1e-9);
+            }
+        }
+        for &(lo, hi) in r.obj_ranges.iter().chain(&r.rhs_ranges) {
+            assert!(lo <= hi);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn get_basis_head() -> Result<()> {
+        let mut m = Model::new("")?;
+        m.set_param(param::OutputFlag, 0)?;
+        let x = add_ctsvar!(m, bounds: 0..3, obj: 1)?;
+        let y = add_ctsvar!(m, bounds: 0.., obj: 1)?;
+        m.add_constr("c1", c!(x + 2 * y <= 10))?;
+        m.add_constr("c2", c!(x - y >= -100))?;
+        m.set_attr(attr::ModelSense, Maximize)?;
+        m.update()?;
+        assert!(matches!(
+            m.get_basis_head(),
+            Err(Error::DataNotAvailable(_))
+        ));
+        m.optimize()?;
+
+        // x is nonbasic at its upper bound, y and the slack of c2 are basic
+        let mut bhead = m.get_basis_head()?;
+        bhead.sort_unstable();
+        assert_eq!(bhead, [1, 3]);
+        let vbasis = m.get_obj_attr_batch(attr::VBasis, vec![x, y])?;
+        assert_eq!(vbasis, [-2, 0]);
+        Ok(())
+    }
+
+    #[test]
+    fn tableau() -> Result<()> {
+        let mut m = Model::new("")?;
+        m.set_param(param::OutputFlag, 0)?;
+        m.set_param(param::Presolve, 0)?;
+        let x = add_ctsvar!(m, bounds: 0..3, obj: 1)?;
+        let y = add_ctsvar!(m, bounds: 0.., obj: 1)?;
+        m.add_constr("c1", c!(x + 2 * y <= 10))?;
+        m.add_constr("c2", c!(x - y >= -100))?;
+        m.set_attr(attr::ModelSense, Maximize)?;
+        m.update()?;
+        assert!(matches!(m.tableau_row(0), Err(Error::DataNotAvailable(_))));
+        m.optimize()?;
+
+        let bhead = m.get_basis_head()?;
+        for (i, &b) in bhead.iter().enumerate() {
+            // the tableau row of a basic variable has a 1 in its own column
+            let row = m.tableau_row(i as i32)?;
+            let k = row.inds.iter().position(|&j| j == b).unwrap();
+            assert!((row.vals[k] - 1.0).abs() < 1e-9);
+            assert!(row.inds.iter().all(|j| (0..4).contains(j)));
+
+            // the tableau column of a basic variable is a unit vector
+            let col = m.tableau_col(b)?;
+            let nonzeros: Vec<_> = col
+                .inds
+                .iter()
+                .zip(&col.vals)
+                .filter(|(_, v)| v.abs() > 1e-9)
+                .collect();
+            assert_eq!(nonzeros.len(), 1);
+            assert_eq!(*nonzeros[0].0, i as i32);
+        }
+        assert!(matches!(m.tableau_col(4), Err(Error::FromAPI(_, _))));
+        Ok(())
+    }
+
+    #[test]
+    fn get_q_constr_expr() -> Result<()> {
+        let mut m = Model::new("")?;
+        let x = add_ctsvar!(m)?;
+        let y = add_ctsvar!(m)?;
+        let qc = m.add_qconstr("qc", c!(x * x + 2 * (x * y) - 3 * y >= 1 - y * y))?;
+        assert_eq!(
+            m.get_q_constr_expr(&qc).err(),
+            Some(Error::ModelUpdateNeeded)
+        );
+        m.update()?;
+
+        let (expr, sense, rhs) = m.get_q_constr_expr(&qc)?;
+        assert!(matches!(sense, ConstrSense::Greater));
+        assert_eq!(rhs, 1.0);
+        assert_eq!(
+            expr.affine_part().iter_terms_sorted().collect::<Vec<_>>(),
+            [(&y, &-3.0)]
+        );
+        assert_eq!(
+            expr.iter_qterms_sorted().collect::<Vec<_>>(),
+            [(&(x, x), &1.0), (&(x, y), &2.0), (&(y, y), &1.0)]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn get_genconstr_indicator() -> Result<()> {
+        let mut m = Model::new("")?;
+        let b = add_binvar!(m)?;
+        let x = add_ctsvar!(m)?;
+        let y = add_ctsvar!(m)?;
+        let gc = m.add_genconstr_indicator("ind", b, false, c!(x + 2 * y >= 3 + y))?;
+        let max = m.add_genconstr_max("max", x, vec![y], None)?;
+        m.update()?;
+
+        let (ind, ind_val, con) = m.get_genconstr_indicator(&gc)?;
+        assert_eq!(ind, b);
+        assert!(!ind_val);
+        assert!(matches!(con.sense, ConstrSense::Greater));
+        let (lhs, _, rhs) = con.into_normalised_linear()?;
+        assert_eq!(rhs, 3.0);
+        assert_eq!(
+            lhs.iter_terms_sorted().collect::<Vec<_>>(),
+            [(&x, &1.0), (&y, &1.0)]
+        );
+
+        assert!(matches!(
+            m.get_genconstr_indicator(&max),
+            Err(Error::InvalidArgument(_))
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn get_genconstr_type() -> Result<()> {
+        let mut m = Model::new("")?;
+        let b = add_binvar!(m)?;
+        let x = add_ctsvar!(m)?;
+        let y = add_ctsvar!(m)?;
+        let gcs = [
+            m.add_genconstr_max("", x, vec![y], None)?,
+            m.add_genconstr_min("", x, vec![y], None)?,
+            m.add_genconstr_abs("", x, y)?,
+            m.add_genconstr_and("", b, vec![b])?,
+            m.add_genconstr_indicator("", b, true, c!(x <= 1))?,
+            m.add_genconstr_pwl("", x, y, vec![(0.0, 0.0), (1.0, 1.0)])?,
+            m.add_genconstr_pow("", x, y, 2.0, "")?,
+            m.add_genconstr_sin("", x, y, "")?,
+            m.add_genconstr_logistic("", x, y, "")?,
+        ];
+        m.update()?;
+        let types = gcs
+            .iter()
+            .map(|gc| m.get_genconstr_type(gc))
+            .collect::<Result<Vec<_>>>()?;
+        assert_eq!(
+            types,
+            [
+                GenConstrType::Max,
+                GenConstrType::Min,
+                GenConstrType::Abs,
+                GenConstrType::And,
+                GenConstrType::Indicator,
+                GenConstrType::Pwl,
+                GenConstrType::Pow,
+                GenConstrType::Sin,
+                GenConstrType::Logistic,
+            ]
+        );
+        assert_eq!(
+            m.get_obj_attr_batch(attr::GenConstrType, gcs.iter().copied())?,
+            types
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn add_vars_batch() -> Result<()> {
+        let mut m = Model::new("")?;
+        let x = add_ctsvar!(m)?;
+        let vars = m.add_vars_batch(vec![
+            ("a".to_string(), Continuous, 1.0, -1.0, 1.0),
+            ("b".to_string(), Integer, 2.0, 0.0, 10.0),
+            ("c".to_string(), Binary, 0.0, 0.0, 1.0),
+        ])?;
+        assert_eq!(vars.len(), 3);
+        assert_eq!(m.num_pending_changes(), 4);
+        m.update()?;
+        assert_eq!(m.get_vars()?, [x, vars[0], vars[1], vars[2]]);
+        assert_eq!(
+            m.get_obj_attr_batch(attr::VarName, vars.iter().copied())?,
+            ["a", "b", "c"]
+        );
+        assert_eq!(
+            m.get_obj_attr_batch(attr::VType, vars.iter().copied())?,
+            [Continuous, Integer, Binary]
+        );
+        assert_eq!(
+            m.get_obj_attr_batch(attr::Obj, vars.iter().copied())?,
+            [1.0, 2.0, 0.0]
+        );
+        assert_eq!(
+            m.get_obj_attr_batch(attr::LB, vars.iter().copied())?,
+            [-1.0, 0.0, 0.0]
+        );
+        assert_eq!(
+            m.get_obj_attr_batch(attr::UB, vars.iter().copied())?,
+            [1.0, 10.0, 1.0]
+        );
+
+        assert!(m.add_vars_batch(vec![])?.is_empty());
+        assert!(matches!(
+            m.add_vars_batch(vec![("d".to_string(), Continuous, f64::NAN, 0.0, 1.0)]),
+            Err(Error::AlgebraicError(_))
+        ));
+        assert_eq!(m.num_pending_changes(), 0);
+        Ok(())
+    }
+
+    #[test]
+    fn add_constrs_distinct_handles() -> Result<()> {
+        let mut m = Model::new("")?;
+        m.set_param(param::OutputFlag, 0)?;
+        let x = add_ctsvar!(m, obj: 1)?;
+        let y = add_ctsvar!(m, obj: 1)?;
+        let constrs = m.add_constrs(vec![(&"c1", c!(x >= 1)), (&"c2", c!(y >= 2))])?;
+        m.optimize()?;
+        assert_eq!(m.status()?, Status::Optimal);
+
+        assert_ne!(constrs[0], constrs[1]);
+        assert_eq!(m.get_index(&constrs[0])?, 0);
+        assert_eq!(m.get_index(&constrs[1])?, 1);
+        assert_eq!(m.get_obj_attr(attr::ConstrName, &constrs[0])?, "c1");
+        assert_eq!(m.get_obj_attr(attr::ConstrName, &constrs[1])?, "c2");
+        assert_eq!(m.get_obj_attr(attr::Pi, &constrs[0])?, 1.0);
+        assert_eq!(m.get_obj_attr(attr::RHS, &constrs[1])?, 2.0);
+        Ok(())
+    }
+
+    #[test]
+    fn add_ranges_distinct_handles() -> Result<()> {
+        let mut m = Model::new("")?;
+        m.set_param(param::OutputFlag, 0)?;
+        let x = add_ctsvar!(m, bounds: 0..5)?;
+        let y = add_ctsvar!(m, bounds: 0..5)?;
+        let (slacks, constrs) = m.add_ranges(vec![
+            (&"r0", c!(x in 0..2)),
+            (&"r1", c!(y in 1..3)),
+            (&"r2", c!(x + y in 0..10)),
+        ])?;
+        m.update()?;
+
+        let var_inds = slacks
+            .iter()
+            .map(|v| m.get_index(v))
+            .collect::<Result<Vec<_>>>()?;
+        // the range variables are added after the existing variables
+        assert_eq!(var_inds, [2, 3, 4]);
+        let constr_inds = constrs
+            .iter()
+            .map(|c| m.get_index(c))
+            .collect::<Result<Vec<_>>>()?;
+        assert_eq!(constr_inds, [0, 1, 2]);
+        assert_eq!(
+            m.get_obj_attr_batch(attr::ConstrName, constrs.iter().copied())?,
+            ["r0", "r1", "r2"]
+        );
+
+        m.set_objective(x + y, Maximize)?;
+        m.optimize()?;
+        assert_eq!(m.status()?, Status::Optimal);
+        assert_eq!(m.get_obj_attr_batch(attr::X, vec![x, y])?, [2.0, 3.0]);
+        // each range is stored as `expr - slack == lb`
+        let slack_vals = m.get_obj_attr_batch(attr::X, slacks)?;
+        for (val, expected) in slack_vals.into_iter().zip(&[2.0, 2.0, 5.0]) {
+            assert!((val - expected).abs() < 1e-6);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn attr_info() -> Result<()> {
+        use crate::attribute::{AttrDataType, AttrObjType};
+        let m = Model::new("")?;
+        let info = |name| {
+            m.get_attr_info(name)
+                .map(|i| (i.datatype, i.attrtype, i.settable))
+        };
+        assert_eq!(info("X")?, (AttrDataType::Double, AttrObjType::Var, false));
+        assert_eq!(info("VType")?, (AttrDataType::Char, AttrObjType::Var, true));
+        assert_eq!(
+            info("NumVars")?,
+            (AttrDataType::Int, AttrObjType::Model, false)
+        );
+        assert_eq!(
+            info("ModelName")?,
+            (AttrDataType::String, AttrObjType::Model, true)
+        );
+        assert_eq!(
+            info("RHS")?,
+            (AttrDataType::Double, AttrObjType::Constr, true)
+        );
+        assert_eq!(
+            info("QCSense")?,
+            (AttrDataType::Char, AttrObjType::QConstr, true)
+        );
+        assert_eq!(
+            info("IISSOS")?,
+            (AttrDataType::Int, AttrObjType::SOS, false)
+        );
+        assert_eq!(
+            info("GenConstrName")?,
+            (AttrDataType::String, AttrObjType::GenConstr, true)
+        );
+        assert!(matches!(
+            m.get_attr_info("NotAnAttribute"),
+            Err(Error::FromAPI(_, _))
+        ));
+        Ok(())
     }
-}
 
-// TODO: check that multi-objective and scenario optimisation work/are usable
+    #[test]
+    fn pwl_obj_round_trip() -> Result<()> {
+        let mut m = Model::new("")?;
+        m.set_param(param::OutputFlag, 0)?;
+        let x = add_ctsvar!(m, bounds: 0..10)?;
+        let y = add_ctsvar!(m, bounds: 0..10, obj: 1)?;
+        let points = vec![(0.0, 4.0), (2.0, 1.0), (6.0, 1.0), (10.0, 5.0)];
+        m.set_pwl_obj(&x, points.clone())?;
+        m.update()?;
+        assert_eq!(m.get_pwl_obj(&x)?, points);
+        assert!(m.get_pwl_obj(&y)?.is_empty());
 
-impl std::convert::From<AsyncModel> for Model {
-    fn from(model: AsyncModel) -> Model {
-        model.0
+        m.optimize()?;
+        assert_eq!(m.get_attr(attr::ObjVal)?, 1.0);
+        Ok(())
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    extern crate self as grb;
+    #[test]
+    fn raw_attrs() -> Result<()> {
+        let mut m = Model::new("")?;
+        m.set_param(param::OutputFlag, 0)?;
+        let x = add_ctsvar!(m, name: "x", bounds: 0..2, obj: 1)?;
+        let y = add_ctsvar!(m, name: "y", bounds: 1..3, obj: 1)?;
+        let c = m.add_constr("c", c!(x + y >= 2))?;
+        m.optimize()?;
+
+        let raw = |name| RawAttr::new(name);
+        assert_eq!(m.get_raw_int_attr(&raw("NumVars")?)?, 2);
+        assert_eq!(m.get_raw_dbl_attr(&raw("ObjVal")?)?, 2.0);
+        assert_eq!(m.get_raw_str_attr(&raw("ModelName")?)?, "");
+        assert_eq!(m.get_raw_dbl_attr_element(&raw("UB")?, &y)?, 3.0);
+        assert_eq!(m.get_raw_str_attr_element(&raw("ConstrName")?, &c)?, "c");
+        assert_eq!(m.get_raw_int_attr_element(&raw("BranchPriority")?, &y)?, 0);
+        assert_eq!(
+            m.get_raw_dbl_attr_batch(&raw("LB")?, vec![x, y])?,
+            [0.0, 1.0]
+        );
+        assert_eq!(
+            m.get_raw_str_attr_batch(&raw("VarName")?, vec![x, y])?,
+            ["x", "y"]
+        );
+        assert_eq!(
+            m.get_raw_dbl_attr_batch(&raw("X")?, vec![x, y])?,
+            m.get_obj_attr_batch(attr::X, vec![x, y])?
+        );
+
+        assert!(matches!(
+            m.get_raw_int_attr(&raw("NotAnAttribute")?),
+            Err(Error::FromAPI(_, _))
+        ));
+        assert!(matches!(
+            m.get_raw_int_attr_element(&raw("UB")?, &x),
+            Err(Error::FromAPI(_, _))
+        ));
+        Ok(())
+    }
 
     #[test]
-    fn model_id_factory() {
-        let mut env = Env::new("").unwrap();
-        env.set(param::OutputFlag, 0).unwrap();
+    fn feas_relax_mapped() -> Result<()> {
+        let mut m = Model::new("")?;
+        m.set_param(param::OutputFlag, 0)?;
+        let x = add_ctsvar!(m, bounds: 0..10)?;
+        let y = add_ctsvar!(m, bounds: 0..10)?;
+        let c1 = m.add_constr("c1", c!(x + y <= 2))?;
+        let c2 = m.add_constr("c2", c!(x == 3))?;
+        let c3 = m.add_constr("c3", c!(y >= 4))?;
+        m.add_constr("c4", c!(x <= 8))?;
+
+        let FeasRelaxSlacks {
+            feas_obj,
+            slacks: pairs,
+        } = m.feas_relax_mapped(
+            RelaxType::Linear,
+            true,
+            std::iter::empty(),
+            std::iter::empty(),
+            vec![(c3, 1.0), (c1, 1.0), (c2, 1.0)],
+        )?;
+        assert!((feas_obj.unwrap() - 5.0).abs() < 1e-6);
+        let constrs: Vec<_> = pairs.iter().map(|&(c, _)| c).collect();
+        // an equality constraint gets two slack variables
+        assert_eq!(constrs, [c3, c1, c2, c2]);
+        let slacks: std::collections::HashSet<_> = pairs.iter().map(|&(_, v)| v).collect();
+        assert_eq!(slacks.len(), 4);
 
-        let mut m1 = Model::with_env("test1", &env).unwrap();
-        let mut m2 = Model::with_env("test2", &env).unwrap();
+        m.optimize()?;
+        let total_violation: f64 = m.get_obj_attr_batch(attr::X, slacks)?.into_iter().sum();
+        // every minimum-cost relaxation violates the constraints by 5 in total
+        assert!((total_violation - 5.0).abs() < 1e-6);
+        Ok(())
+    }
 
-        let x1 = add_var!(m1, Binary,  name:"x1").unwrap();
-        let x2 = add_var!(m2, Binary,  name:"x2").unwrap();
-        assert_ne!(m1.id, m2.id);
+    #[test]
+    fn reset_with() -> Result<()> {
+        let mut m = Model::new("")?;
+        m.set_param(param::OutputFlag, 0)?;
+        let x = add_intvar!(m, bounds: 0..5)?;
+        m.set_objective(x, Maximize)?;
+        m.set_obj_attr(attr::Start, &x, 2.0)?;
+        m.optimize()?;
+        assert_eq!(m.status()?, Status::Optimal);
 
-        assert_eq!(x1.model_id, m1.id);
-        assert_eq!(x1.id, 0);
-        assert_eq!(x2.model_id, m2.id);
-        assert_eq!(x2.id, 0);
+        m.reset_with(false)?;
+        assert_eq!(m.status()?, Status::Loaded);
+        assert_eq!(m.get_obj_attr(attr::Start, &x)?, 2.0);
+
+        m.optimize()?;
+        m.reset_with(true)?;
+        assert_eq!(m.status()?, Status::Loaded);
+        assert_eq!(
+            m.get_obj_attr(attr::Start, &x)?,
+            crate::constants::GRB_UNDEFINED
+        );
+        Ok(())
     }
 
     #[test]
-    fn eager_update() {
-        let mut model = Model::new("test").unwrap();
-        assert_eq!(model.get_param(param::UpdateMode).unwrap(), 1);
-        assert!(!model.update_mode_lazy().unwrap());
-        let x = add_binvar!(model, name:"x").unwrap();
-        let y = add_binvar!(model, name:"y").unwrap();
-        let c1 = model.add_constr("c1", c!(x + y <= 1)).unwrap(); // should work fine
+    fn add_indicator() -> Result<()> {
+        let mut m = Model::new("")?;
+        m.set_param(param::OutputFlag, 0)?;
+        let x = add_ctsvar!(m, bounds: 0..10)?;
+        let b = add_binvar!(m)?;
+        let c = add_binvar!(m)?;
+        let g1 = m.add_indicator("g1", c!(b == 1 >> (x <= 3)))?;
+        let g2 = m.add_indicator("g2", c!((c == 0) >> (x <= 5)))?;
+        m.update()?;
+        assert_eq!(m.get_attr(attr::NumGenConstrs)?, 2);
+        let (ind, val, _) = m.get_genconstr_indicator(&g1)?;
+        assert_eq!((ind, val), (b, true));
+        let (ind, val, _) = m.get_genconstr_indicator(&g2)?;
+        assert_eq!((ind, val), (c, false));
+
+        m.add_constr("fix_b", c!(b == 1))?;
+        m.set_objective(x, Maximize)?;
+        m.optimize()?;
+        assert_eq!(m.get_attr(attr::ObjVal)?, 3.0);
+        Ok(())
+    }
 
-        assert_eq!(model.get_attr(attr::NumVars).unwrap(), 0);
-        assert!(model.get_index(&x).is_err());
-        assert_eq!(model.get_index_build(&x).unwrap(), 0);
-        assert!(model.get_index(&y).is_err());
-        assert_eq!(model.get_index_build(&y).unwrap(), 1);
-        assert_eq!(model.get_index_build(&c1).unwrap(), 0);
+    #[test]
+    fn add_var_macro_bounds() -> Result<()> {
+        let mut m = Model::new("")?;
+        let n = 3usize;
+        let vars = [
+            add_ctsvar!(m, bounds: -5..5)?,
+            add_ctsvar!(m, bounds: -1e9..)?,
+            add_ctsvar!(m, bounds: ..-0.5)?,
+            add_intvar!(m, bounds: 0..n + 1)?,
+            add_intvar!(m, bounds: -(n as i32) * 2..=-1)?,
+        ];
+        m.update()?;
+        let lb = m.get_obj_attr_batch(attr::LB, vars)?;
+        let ub = m.get_obj_attr_batch(attr::UB, vars)?;
+        assert_eq!(lb, [-5.0, -1e9, -INFINITY, 0.0, -6.0]);
+        assert_eq!(ub, [5.0, INFINITY, -0.5, 4.0, -1.0]);
+        Ok(())
+    }
 
-        model.update().unwrap();
-        assert_eq!(model.get_attr(attr::NumVars).unwrap(), 2);
-        assert_eq!(model.get_index(&x).unwrap(), 0);
-        assert_eq!(model.get_index(&y).unwrap(), 1);
+    #[test]
+    fn scenario_models() -> Result<()> {
+        let mut m = Model::new("")?;
+        m.set_param(param::OutputFlag, 0)?;
+        let x = add_binvar!(m)?;
+        let y = add_binvar!(m)?;
+        m.add_constr("c", c!(x + y <= 2))?;
+        m.set_attr(attr::NumScenarios, 3)?;
+        m.set_param(param::ScenarioNumber, 1)?;
+        m.set_obj_attr(attr::ScenNObj, &x, -1.0)?;
+        m.set_param(param::ScenarioNumber, 2)?;
+        m.set_obj_attr(attr::ScenNObj, &x, -1.0)?;
+        m.set_obj_attr(attr::ScenNObj, &y, -1.0)?;
+        m.update()?;
+
+        let models = m.scenario_models()?;
+        assert_eq!(models.len(), 3);
+        assert_eq!(m.get_param(param::ScenarioNumber)?, 2);
+        for (mut sm, expected) in models.into_iter().zip([0.0, -1.0, -2.0]) {
+            assert_eq!(sm.get_attr(attr::NumScenarios)?, 0);
+            sm.optimize()?;
+            assert_eq!(sm.get_attr(attr::ObjVal)?.round(), expected);
+        }
+        Ok(())
+    }
 
-        let z = add_binvar!(model, name:"z").unwrap();
-        assert_eq!(model.get_attr(attr::NumVars).unwrap(), 2);
-        assert_eq!(model.get_index(&x).unwrap(), 0);
-        assert_eq!(model.get_index(&y).unwrap(), 1);
-        assert!(model.get_index(&z).is_err());
-        assert_eq!(model.get_index_build(&z).unwrap(), 2);
+    #[test]
+    fn solution_map() -> Result<()> {
+        let mut m = Model::new("")?;
+        m.set_param(param::OutputFlag, 0)?;
+        let x = add_ctsvar!(m, name: "x", bounds: 0..2)?;
+        let y = add_ctsvar!(m, name: "y", bounds: 0..3)?;
+        m.add_constr("c", c!(x + y <= 4))?;
+        m.set_objective(2 * x + y, Maximize)?;
+        m.update()?;
+        assert!(matches!(m.solution_map(), Err(Error::DataNotAvailable(_))));
 
-        model.remove(y).unwrap();
-        let c2 = model.add_constr("c2", c!(z + y <= 1)).unwrap(); // I know it's weird, because y is removed, but that's what Gurobi does
-        assert_eq!(model.get_index_build(&c2).unwrap(), 1);
-        assert_eq!(model.get_attr(attr::NumVars).unwrap(), 2);
-        assert_eq!(model.get_index(&x).unwrap(), 0);
-        assert_eq!(model.get_index(&y), Err(Error::ModelObjectRemoved)); // No longer available
-        assert!(model.get_index(&z).is_err());
-        assert_eq!(model.get_index_build(&z).unwrap(), 2);
+        m.optimize()?;
+        let sol = m.solution_map()?;
+        assert_eq!(sol, [("x".to_string(), 2.0), ("y".to_string(), 2.0)]);
 
-        let w = add_binvar!(model, name:"w").unwrap();
-        assert_eq!(model.get_attr(attr::NumVars).unwrap(), 2);
-        assert_eq!(model.get_index(&x).unwrap(), 0);
-        assert_eq!(model.get_index(&y), Err(Error::ModelObjectRemoved)); // No longer available
-        assert!(model.get_index(&z).is_err());
-        assert_eq!(model.get_index_build(&z).unwrap(), 2);
-        assert!(model.get_index(&w).is_err());
-        assert_eq!(model.get_index_build(&w).unwrap(), 3);
+        add_ctsvar!(m)?;
+        assert_eq!(m.solution_map(), Err(Error::ModelUpdateNeeded));
+        Ok(())
+    }
 
-        model.update().unwrap();
-        assert_eq!(model.get_attr(attr::NumVars).unwrap(), 3);
-        assert_eq!(model.get_attr(attr::NumNZs).unwrap(), 2); // start with 2 constraints with 2 vars = 2 x 2 = 4, minus where y appears twice = 4 - 2 = 2
-        assert_eq!(model.get_index(&x).unwrap(), 0);
-        assert!(model.get_index(&y).is_err());
-        assert_eq!(model.get_index(&z).unwrap(), 1);
-        assert_eq!(model.get_index(&w).unwrap(), 2);
+    #[test]
+    #[cfg(feature = "serde")]
+    fn solution_json() -> Result<()> {
+        let mut m = Model::new("")?;
+        m.set_param(param::OutputFlag, 0)?;
+        let x = add_intvar!(m, name: "x", bounds: 0..2)?;
+        let y = add_ctsvar!(m, name: "y", bounds: 0..3)?;
+        m.add_constr("c", c!(x + y <= 4))?;
+        m.set_objective(2 * x + y, Maximize)?;
+        m.optimize()?;
 
+        let sol = m.solution_json()?;
+        let info = &sol["SolutionInfo"];
+        assert_eq!(info["Status"], 2);
+        assert_eq!(info["ObjVal"], 6.0);
         assert_eq!(
-            model.get_obj_attr(attr::VarName, &x).unwrap(),
-            "x".to_string()
+            info["SolCount"].as_i64(),
+            Some(m.get_attr(attr::SolCount)? as i64)
         );
         assert_eq!(
-            model.get_obj_attr(attr::VarName, &z).unwrap(),
-            "z".to_string()
+            info["NodeCount"].as_f64(),
+            Some(m.get_attr(attr::NodeCount)?)
         );
         assert_eq!(
-            model.get_obj_attr(attr::VarName, &w).unwrap(),
-            "w".to_string()
+            sol["Vars"],
+            serde_json::json!([{ "VarName": "x", "X": 2.0 }, { "VarName": "y", "X": 2.0 }])
         );
+        Ok(())
     }
 
     #[test]
-    fn lazy_update() {
-        let mut env = Env::new("").unwrap();
-        env.set(param::OutputFlag, 0).unwrap();
-        env.set(param::UpdateMode, 0).unwrap();
-        let mut model = Model::with_env("bug", &env).unwrap();
-        assert!(model.update_mode_lazy().unwrap());
+    fn model_bytes_round_trip() -> Result<()> {
+        let mut m = Model::new("bytes")?;
+        m.set_param(param::OutputFlag, 0)?;
+        let x = add_intvar!(m, name: "x", bounds: 0..10)?;
+        let y = add_ctsvar!(m, name: "y", bounds: 0..10)?;
+        m.add_constr("c1", c!(x + 2 * y <= 7))?;
+        m.add_constr("c2", c!(3 * x - y >= -2))?;
+        m.set_objective(x + y, Maximize)?;
+        m.optimize()?;
+        let obj = m.get_attr(attr::ObjVal)?;
+
+        for format in [
+            ModelFormat::Mps,
+            ModelFormat::Rew,
+            ModelFormat::Lp,
+            ModelFormat::Rlp,
+        ] {
+            let data = m.write_to_bytes(format)?;
+            assert!(!data.is_empty());
+            let mut copy = Model::from_bytes(&data, format, m.get_env())?;
+            copy.set_param(param::OutputFlag, 0)?;
+            assert_eq!(copy.get_attr(attr::NumVars)?, 2);
+            assert_eq!(copy.get_attr(attr::NumConstrs)?, 2);
+            assert_eq!(copy.get_attr(attr::NumIntVars)?, 1);
+            copy.optimize()?;
+            assert!((copy.get_attr(attr::ObjVal)? - obj).abs() < 1e-6);
+        }
 
-        let x = add_binvar!(model, name:"x").unwrap();
-        let y = add_binvar!(model, name:"y").unwrap();
-        assert_eq!(model.get_attr(attr::NumVars).unwrap(), 0);
-        assert_eq!(
-            model.get_index_build(&x).unwrap_err(),
-            Error::ModelObjectPending
-        );
-        assert_eq!(
-            model.get_index_build(&y).unwrap_err(),
-            Error::ModelObjectPending
-        );
-        model.add_constr("c1", c!(x + y <= 1)).unwrap_err();
+        let lp = String::from_utf8(m.write_to_bytes(ModelFormat::Lp)?).unwrap();
+        assert!(lp.contains("c1:"));
+        assert!(Model::from_bytes(b"not a model", ModelFormat::Mps, m.get_env()).is_err());
+        Ok(())
+    }
 
-        model.update().unwrap();
-        assert_eq!(model.get_attr(attr::NumVars).unwrap(), 2);
-        assert_eq!(model.get_index(&x).unwrap(), 0);
-        assert_eq!(model.get_index(&y).unwrap(), 1);
-        let c1 = model.add_constr("c1", c!(x + y <= 1)).unwrap();
+    #[test]
+    fn var_and_constr_queries() -> Result<()> {
+        let mut m = Model::new("")?;
+        let x = add_binvar!(m)?;
+        let y = add_intvar!(m, bounds: -3..7)?;
+        let z = add_ctsvar!(m, bounds: ..)?;
+        let c1 = m.add_constr("c1", c!(x + y <= 1))?;
+        let c2 = m.add_constr("c2", c!(y - z == 0))?;
+        let c3 = m.add_constr("c3", c!(x + z >= -1))?;
+        m.update()?;
+
+        assert_eq!(x.vtype(&m)?, Binary);
+        assert_eq!(y.vtype(&m)?, Integer);
+        assert_eq!(z.vtype(&m)?, Continuous);
+        assert_eq!(x.bounds(&m)?, (0.0, 1.0));
+        assert_eq!(y.bounds(&m)?, (-3.0, 7.0));
+        assert_eq!(z.bounds(&m)?, (-INFINITY, INFINITY));
+
+        assert!(matches!(c1.sense(&m)?, ConstrSense::Less));
+        assert!(matches!(c2.sense(&m)?, ConstrSense::Equal));
+        assert!(matches!(c3.sense(&m)?, ConstrSense::Greater));
+
+        let w = add_ctsvar!(m)?;
+        assert_eq!(w.vtype(&m), Err(Error::ModelObjectPending));
+        Ok(())
+    }
 
-        model.remove(y).unwrap();
-        let z = add_binvar!(model, name:"z").unwrap();
-        let w = add_binvar!(model, name:"w").unwrap();
-        assert_eq!(model.get_attr(attr::NumVars).unwrap(), 2);
-        assert_eq!(model.get_index(&x).unwrap(), 0);
-        assert_eq!(model.get_index(&y).unwrap_err(), Error::ModelObjectRemoved); // this is updated instantly
+    #[test]
+    fn set_var_hints() -> Result<()> {
+        let mut m = Model::new("")?;
+        m.set_param(param::OutputFlag, 0)?;
+        let x = add_binvar!(m)?;
+        let y = add_binvar!(m)?;
+        let z = add_intvar!(m, bounds: 0..5)?;
+        m.add_constr("c", c!(x + y + z <= 4))?;
+        m.set_objective(x + y + z, Maximize)?;
+        m.update()?;
+
+        m.set_var_hints([(x, 1.0, Some(5)), (y, 0.0, None), (z, 3.0, Some(-2))])?;
+        m.update()?;
         assert_eq!(
-            model.get_index_build(&z).unwrap_err(),
-            Error::ModelObjectPending
+            m.get_obj_attr_batch(attr::VarHintVal, [x, y, z])?,
+            [1.0, 0.0, 3.0]
         );
         assert_eq!(
-            model.get_index_build(&w).unwrap_err(),
-            Error::ModelObjectPending
+            m.get_obj_attr_batch(attr::VarHintPri, [x, y, z])?,
+            [5, 0, -2]
         );
 
-        model.update().unwrap();
-        assert_eq!(model.get_attr(attr::NumVars).unwrap(), 3);
-        assert_eq!(model.get_index(&x).unwrap(), 0);
-        assert_eq!(model.get_index(&y).unwrap_err(), Error::ModelObjectRemoved);
-        assert_eq!(model.get_index(&z).unwrap(), 1);
-        assert_eq!(model.get_index(&w).unwrap(), 2);
-        assert_eq!(model.get_index(&c1).unwrap(), 0);
+        m.optimize()?;
+        assert_eq!(m.get_attr(attr::ObjVal)?, 4.0);
 
+        let w = add_binvar!(m)?;
         assert_eq!(
-            model.get_obj_attr(attr::VarName, &x).unwrap(),
-            "x".to_string()
-        );
-        assert_eq!(
-            model.get_obj_attr(attr::VarName, &z).unwrap(),
-            "z".to_string()
-        );
-        assert_eq!(
-            model.get_obj_attr(attr::VarName, &w).unwrap(),
-            "w".to_string()
+            m.set_var_hints([(w, 1.0, None)]),
+            Err(Error::ModelObjectPending)
         );
+        Ok(())
+    }
+
+    #[test]
+    fn add_mip_start() -> Result<()> {
+        let mut m = Model::new("")?;
+        m.set_param(param::OutputFlag, 0)?;
+        let x = add_intvar!(m, bounds: 0..10)?;
+        let y = add_intvar!(m, bounds: 0..10)?;
+        m.add_constr("c", c!(x + y <= 10))?;
+        m.set_objective(x + 2 * y, Maximize)?;
+        m.update()?;
+        assert_eq!(m.num_start_vectors()?, 0);
+
+        assert_eq!(m.add_mip_start([(x, 1.0), (y, 2.0)])?, 0);
+        assert_eq!(m.add_mip_start([(y, 5.0)])?, 1);
+        assert_eq!(m.num_start_vectors()?, 2);
+        assert_eq!(m.get_param(param::StartNumber)?, 1);
+        m.update()?;
+
+        let undefined = crate::constants::GRB_UNDEFINED;
+        assert_eq!(m.get_obj_attr_batch(attr::Start, [x, y])?, [undefined, 5.0]);
+        m.set_param(param::StartNumber, 0)?;
+        assert_eq!(m.get_obj_attr_batch(attr::Start, [x, y])?, [1.0, 2.0]);
+
+        m.optimize()?;
+        assert_eq!(m.get_attr(attr::ObjVal)?, 20.0);
+        Ok(())
+    }
+
+    #[test]
+    fn get_objective() -> Result<()> {
+        let mut m = Model::new("")?;
+        m.set_param(param::OutputFlag, 0)?;
+        let x = add_ctsvar!(m, bounds: 3..3)?;
+        let y = add_ctsvar!(m, bounds: 0..1)?;
+        m.set_objective(2 * x + x * x + 1, Minimize)?;
+
+        let (obj, sense) = m.get_objective()?;
+        assert_eq!(sense, Minimize);
+        assert!(!obj.is_linear());
+        let obj = obj.into_quadexpr();
+        assert_eq!(obj.num_qterms(), 1);
+        assert_eq!(obj.num_terms(), 1);
+        assert_eq!(obj.get_offset(), 1.0);
+        assert!(obj.iter_terms().all(|(&v, _)| v != y));
+
+        m.optimize()?;
+        let val = obj.get_value(&m)?;
+        assert!((val - 16.0).abs() < 1e-6); // 2*3 + 3*3 + 1
+        assert!((val - m.get_attr(attr::ObjVal)?).abs() < 1e-6);
+        Ok(())
+    }
+
+    #[test]
+    fn mip_gap() -> Result<()> {
+        let mut m = Model::new("")?;
+        m.set_param(param::OutputFlag, 0)?;
+        let x = add_intvar!(m, bounds: 0..10)?;
+        let y = add_intvar!(m, bounds: 0..10)?;
+        m.add_constr("c", c!(3 * x + 5 * y <= 17))?;
+        m.set_objective(4 * x + 7 * y, Maximize)?;
+        m.optimize()?;
+
+        let gap = m.mip_gap()?;
+        assert!((0.0..=1.0).contains(&gap));
+        assert!(m.obj_bound()? >= m.get_attr(attr::ObjVal)? - 1e-6);
+        assert!(m.obj_bound_c()? >= m.get_attr(attr::ObjVal)? - 1e-6);
+
+        let mut lp = Model::new("")?;
+        lp.set_param(param::OutputFlag, 0)?;
+        let z = add_ctsvar!(lp, bounds: 0..1)?;
+        lp.set_objective(z, Maximize)?;
+        lp.optimize()?;
+        assert!(matches!(lp.mip_gap(), Err(Error::DataNotAvailable(_))));
+        Ok(())
+    }
+
+    #[test]
+    fn generic_names() -> Result<()> {
+        let mut m = Model::new("")?;
+        let x = add_ctsvar!(m, name: "x")?;
+        let c = m.add_constr("c", c!(x <= 1))?;
+        m.update()?;
+        assert_eq!(m.get_name(&x)?, "x");
+        assert_eq!(m.get_name(&c)?, "c");
+
+        m.set_name(&x, "y")?;
+        m.set_name(&c, "d")?;
+        m.update()?;
+        assert_eq!(m.get_name(&x)?, "y");
+        assert_eq!(m.get_name(&c)?, "d");
+        assert_eq!(m.get_obj_attr(attr::VarName, &x)?, "y");
+        assert_eq!(m.get_obj_attr(attr::ConstrName, &c)?, "d");
+        Ok(())
     }
 
     #[test]
@@ -2365,6 +6890,39 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn presolved_model_copies_env() -> Result<()> {
+        let mut m = Model::new("")?;
+        m.set_param(param::OutputFlag, 0)?;
+        let x = add_intvar!(m, bounds: 0..10)?;
+        let y = add_intvar!(m, bounds: 0..10)?;
+        let z = add_ctsvar!(m, bounds: 0..10)?;
+        m.add_constr("fix", c!(y == 2))?;
+        m.add_constr("c1", c!(x + y + z <= 7))?;
+        m.add_constr("c2", c!(x - z >= 1))?;
+        m.set_objective(x + 2 * y + z, Maximize)?;
+
+        let mut presolved = m.presolve()?;
+        assert!(presolved.get_attr(attr::NumVars)? < 3);
+        assert_eq!(
+            presolved.get_vars()?.len(),
+            presolved.get_attr(attr::NumVars)? as usize
+        );
+        assert_eq!(presolved.get_env().get(param::OutputFlag)?, 0);
+        m.get_env_mut().set(param::OutputFlag, 1)?;
+        assert_eq!(presolved.get_env().get(param::OutputFlag)?, 0);
+        assert_ne!(m.get_env().as_ptr(), presolved.get_env().as_ptr());
+
+        m.set_param(param::OutputFlag, 0)?;
+        m.optimize()?;
+        presolved.optimize()?;
+        assert_eq!(
+            presolved.get_attr(attr::ObjVal)?.round(),
+            m.get_attr(attr::ObjVal)?.round()
+        );
+        Ok(())
+    }
+
     #[test]
     fn read_model_copies_env() -> Result<()> {
         let env = Env::new("")?;
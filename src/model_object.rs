@@ -2,8 +2,11 @@ use fnv::FnvHashMap;
 use std::fmt::Debug;
 use std::hash::Hash;
 
+use crate::attribute::{
+    ConstrStrAttr, GenConstrStrAttr, ObjAttrGet, ObjAttrSet, QConstrStrAttr, VarStrAttr,
+};
 use crate::ffi;
-use crate::Model;
+use crate::{ConstrSense, Model, VarType};
 use crate::{Error, Result};
 
 mod private_traits {
@@ -33,6 +36,25 @@ pub trait ModelObject: ModelObjectPrivate + Debug {
     fn id(&self) -> u32;
 }
 
+/// Model objects which have a name attribute: [`Var`], [`Constr`], [`GenConstr`] and [`QConstr`].
+///
+/// This allows generic code to query and set names using [`Model::get_name`] and [`Model::set_name`].
+pub trait NamedModelObject: ModelObject {
+    /// The type of the name attribute
+    type NameAttr: ObjAttrGet<Self, String> + for<'a> ObjAttrSet<Self, &'a str>;
+    /// The name attribute for this object type, eg [`attr::VarName`](crate::attr::VarName) for [`Var`]
+    const NAME_ATTR: Self::NameAttr;
+}
+
+macro_rules! impl_named_model_obj {
+    ($t:ty, $attr_ty:ty, $attr:expr) => {
+        impl NamedModelObject for $t {
+            type NameAttr = $attr_ty;
+            const NAME_ATTR: $attr_ty = $attr;
+        }
+    };
+}
+
 macro_rules! create_model_obj_ty {
     ($t:ident, $model_attr:ident, $delfunc:path, $doc:literal) => {
         #[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
@@ -98,6 +120,37 @@ create_model_obj_ty!(SOS, sos, ffi::GRBdelsos,
  To interact with the attributes of a constraint, use [`Model::get_obj_attr`] and [`Model::set_obj_attr`]"
 );
 
+impl_named_model_obj!(Var, VarStrAttr, VarStrAttr::VarName);
+impl_named_model_obj!(Constr, ConstrStrAttr, ConstrStrAttr::ConstrName);
+impl_named_model_obj!(GenConstr, GenConstrStrAttr, GenConstrStrAttr::GenConstrName);
+impl_named_model_obj!(QConstr, QConstrStrAttr, QConstrStrAttr::QCName);
+
+impl Var {
+    /// Query the type of the variable (the `VType` attribute).
+    ///
+    /// Shorthand for `model.get_obj_attr(attr::VType, &var)`.
+    pub fn vtype(&self, model: &Model) -> Result<VarType> {
+        model.get_obj_attr(crate::attr::VType, self)
+    }
+
+    /// Query the lower and upper bounds of the variable (the `LB` and `UB` attributes).
+    pub fn bounds(&self, model: &Model) -> Result<(f64, f64)> {
+        Ok((
+            model.get_obj_attr(crate::attr::LB, self)?,
+            model.get_obj_attr(crate::attr::UB, self)?,
+        ))
+    }
+}
+
+impl Constr {
+    /// Query the sense of the constraint (the `Sense` attribute).
+    ///
+    /// Shorthand for `model.get_obj_attr(attr::Sense, &constr)`.
+    pub fn sense(&self, model: &Model) -> Result<ConstrSense> {
+        model.get_obj_attr(crate::attr::Sense, self)
+    }
+}
+
 #[derive(Debug, Clone, Copy, Eq, PartialEq)]
 enum IdxState {
     Present(i32),
@@ -130,6 +183,8 @@ pub struct IdxManager<T: Hash + Eq> {
     model_id: u32,
     order: Vec<T>,
     lookup: FnvHashMap<T, IdxState>,
+    // indices of objects removed in lazy update mode, which have yet to be deleted in Gurobi
+    deferred_removals: Vec<i32>,
 }
 
 impl<T: ModelObject> IdxManager<T> {
@@ -154,6 +209,7 @@ impl<T: ModelObject> IdxManager<T> {
             next_id: 0,
             update_action: UpdateAction::Noop,
             update_model: false,
+            deferred_removals: Vec::new(),
         }
     }
 
@@ -194,12 +250,23 @@ impl<T: ModelObject> IdxManager<T> {
         self.update_model
     }
 
+    /// Number of objects which have been added or removed since the last update.
+    pub(crate) fn num_pending(&self) -> usize {
+        self.lookup
+            .values()
+            .filter(|s| !matches!(s, IdxState::Present(_)))
+            .count()
+    }
+
     pub(crate) fn objects(&self) -> &[T] {
         assert!(!self.update_model);
         self.order.as_slice()
     }
 
-    pub(crate) fn remove(&mut self, o: T, _update_lazy: bool) -> Result<()> {
+    /// Mark an object as removed.  In lazy update mode, the object's index is queued and the actual
+    /// removal from the Gurobi model is deferred until it is flushed by the model (see
+    /// [`IdxManager::deferred_removals`]).
+    pub(crate) fn remove(&mut self, o: T, update_lazy: bool) -> Result<()> {
         if o.model_id() != self.model_id {
             return Err(Error::ModelObjectMismatch);
         }
@@ -207,7 +274,12 @@ impl<T: ModelObject> IdxManager<T> {
         let state = self.lookup.get_mut(&o).ok_or(Error::ModelObjectRemoved)?;
         match *state {
             IdxState::Build(_) | IdxState::Pending => return Err(Error::ModelObjectPending),
-            IdxState::Present(idx) => *state = IdxState::Removed(idx),
+            IdxState::Present(idx) => {
+                *state = IdxState::Removed(idx);
+                if update_lazy {
+                    self.deferred_removals.push(idx);
+                }
+            }
             IdxState::Removed(_) => return Err(Error::ModelObjectRemoved),
         }
         self.update_model = true;
@@ -216,6 +288,16 @@ impl<T: ModelObject> IdxManager<T> {
         Ok(())
     }
 
+    /// Indices of objects whose removal has been deferred.
+    pub(crate) fn deferred_removals(&self) -> &[i32] {
+        &self.deferred_removals
+    }
+
+    /// Forget the deferred removals, once they have been passed on to Gurobi.
+    pub(crate) fn clear_deferred_removals(&mut self) {
+        self.deferred_removals.clear();
+    }
+
     pub fn add_new(&mut self, update_lazy: bool) -> T {
         debug_assert_eq!(self.lookup.len(), self.order.len());
         let o = T::from_raw(self.next_id, self.model_id);
@@ -0,0 +1,274 @@
+//! Nonlinear expressions for general nonlinear constraints, available with the `gurobi12` feature.
+//!
+//! A nonlinear constraint $y = f(x)$ is added with [`Model::add_genconstr_nl`], where $f(x)$ is an [`NlExpr`].
+//! Expressions are built from variables and constants using the arithmetic operators and the methods on
+//! [`NlExpr`], for example:
+//! ```
+//! # use grb::prelude::*;
+//! use grb::nl::NlExpr;
+//! let mut m = Model::new("model")?;
+//! let x = add_ctsvar!(m, bounds: 0..1)?;
+//! let y = add_ctsvar!(m, bounds: 0..1)?;
+//! let z = add_ctsvar!(m, bounds: ..)?;
+//! // z = sin(2.5x) + e^y / 3
+//! let f = (2.5 * NlExpr::from(x)).sin() + NlExpr::from(y).exp() / 3.0;
+//! m.add_genconstr_nl("nl", z, f)?;
+//! # Ok::<(), grb::Error>(())
+//! ```
+//! See the [manual](https://docs.gurobi.com/projects/optimizer/en/current/features/nonlinear.html) for details.
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+use crate::{Model, Result, Var};
+
+/// Operation codes for the nodes of an expression tree, see `GRB_OPCODE_*` in the Gurobi C API.
+mod opcode {
+    pub const CONSTANT: i32 = 0;
+    pub const VARIABLE: i32 = 1;
+    pub const PLUS: i32 = 2;
+    pub const MINUS: i32 = 3;
+    pub const MULTIPLY: i32 = 4;
+    pub const DIVIDE: i32 = 5;
+    pub const UMINUS: i32 = 6;
+    pub const SQUARE: i32 = 7;
+    pub const SQRT: i32 = 8;
+    pub const SIN: i32 = 9;
+    pub const COS: i32 = 10;
+    pub const TAN: i32 = 11;
+    pub const POW: i32 = 12;
+    pub const EXP: i32 = 13;
+    pub const LOG: i32 = 14;
+    pub const LOG2: i32 = 15;
+    pub const LOG10: i32 = 16;
+    pub const LOGISTIC: i32 = 17;
+}
+
+#[derive(Debug, Clone)]
+enum Node {
+    Constant(f64),
+    Var(Var),
+    Op(i32, Vec<NlExpr>),
+}
+
+/// A nonlinear expression tree over [`Var`]s and constants.  See the [module-level docs](self) for an example.
+#[derive(Debug, Clone)]
+pub struct NlExpr(Node);
+
+impl NlExpr {
+    fn op(opcode: i32, children: Vec<NlExpr>) -> NlExpr {
+        NlExpr(Node::Op(opcode, children))
+    }
+
+    /// Combine two expressions with an associative operator, flattening nested applications so that
+    /// `a + b + c` becomes a single node with three children.
+    fn associative(self, opcode: i32, rhs: NlExpr) -> NlExpr {
+        let mut children = match self.0 {
+            Node::Op(op, children) if op == opcode => children,
+            node => vec![NlExpr(node)],
+        };
+        match rhs.0 {
+            Node::Op(op, c) if op == opcode => children.extend(c),
+            node => children.push(NlExpr(node)),
+        }
+        NlExpr::op(opcode, children)
+    }
+
+    /// A constant expression
+    pub fn constant(val: f64) -> NlExpr {
+        NlExpr(Node::Constant(val))
+    }
+
+    /// $x^2$
+    pub fn square(self) -> NlExpr {
+        NlExpr::op(opcode::SQUARE, vec![self])
+    }
+
+    /// $\sqrt{x}$
+    pub fn sqrt(self) -> NlExpr {
+        NlExpr::op(opcode::SQRT, vec![self])
+    }
+
+    /// $\sin(x)$
+    pub fn sin(self) -> NlExpr {
+        NlExpr::op(opcode::SIN, vec![self])
+    }
+
+    /// $\cos(x)$
+    pub fn cos(self) -> NlExpr {
+        NlExpr::op(opcode::COS, vec![self])
+    }
+
+    /// $\tan(x)$
+    pub fn tan(self) -> NlExpr {
+        NlExpr::op(opcode::TAN, vec![self])
+    }
+
+    /// $x^a$
+    pub fn pow(self, exponent: impl Into<NlExpr>) -> NlExpr {
+        NlExpr::op(opcode::POW, vec![self, exponent.into()])
+    }
+
+    /// $e^x$
+    pub fn exp(self) -> NlExpr {
+        NlExpr::op(opcode::EXP, vec![self])
+    }
+
+    /// $\ln(x)$
+    pub fn log(self) -> NlExpr {
+        NlExpr::op(opcode::LOG, vec![self])
+    }
+
+    /// $\log_2(x)$
+    pub fn log2(self) -> NlExpr {
+        NlExpr::op(opcode::LOG2, vec![self])
+    }
+
+    /// $\log_{10}(x)$
+    pub fn log10(self) -> NlExpr {
+        NlExpr::op(opcode::LOG10, vec![self])
+    }
+
+    /// $\frac{1}{1 + e^{-x}}$
+    pub fn logistic(self) -> NlExpr {
+        NlExpr::op(opcode::LOGISTIC, vec![self])
+    }
+
+    /// Flatten the expression tree into the `(opcode, data, parent)` arrays expected by `GRBaddgenconstrNL`.
+    /// Nodes are listed in pre-order, so every node comes after its parent and children keep their order.
+    pub(crate) fn flatten(&self, model: &Model) -> Result<(Vec<i32>, Vec<f64>, Vec<i32>)> {
+        fn visit(
+            expr: &NlExpr,
+            parent: i32,
+            model: &Model,
+            arrays: &mut (Vec<i32>, Vec<f64>, Vec<i32>),
+        ) -> Result<()> {
+            let idx = arrays.0.len() as i32;
+            let (op, data) = match &expr.0 {
+                Node::Constant(val) => (opcode::CONSTANT, *val),
+                Node::Var(var) => (opcode::VARIABLE, model.get_index_build(var)? as f64),
+                Node::Op(op, _) => (*op, -1.0),
+            };
+            arrays.0.push(op);
+            arrays.1.push(data);
+            arrays.2.push(parent);
+            if let Node::Op(_, children) = &expr.0 {
+                for child in children {
+                    visit(child, idx, model, arrays)?;
+                }
+            }
+            Ok(())
+        }
+
+        let mut arrays = (Vec::new(), Vec::new(), Vec::new());
+        visit(self, -1, model, &mut arrays)?;
+        Ok(arrays)
+    }
+}
+
+impl From<Var> for NlExpr {
+    fn from(var: Var) -> NlExpr {
+        NlExpr(Node::Var(var))
+    }
+}
+
+impl From<f64> for NlExpr {
+    fn from(val: f64) -> NlExpr {
+        NlExpr::constant(val)
+    }
+}
+
+impl<T: Into<NlExpr>> Add<T> for NlExpr {
+    type Output = NlExpr;
+    fn add(self, rhs: T) -> NlExpr {
+        self.associative(opcode::PLUS, rhs.into())
+    }
+}
+
+impl<T: Into<NlExpr>> Sub<T> for NlExpr {
+    type Output = NlExpr;
+    fn sub(self, rhs: T) -> NlExpr {
+        NlExpr::op(opcode::MINUS, vec![self, rhs.into()])
+    }
+}
+
+impl<T: Into<NlExpr>> Mul<T> for NlExpr {
+    type Output = NlExpr;
+    fn mul(self, rhs: T) -> NlExpr {
+        self.associative(opcode::MULTIPLY, rhs.into())
+    }
+}
+
+impl<T: Into<NlExpr>> Div<T> for NlExpr {
+    type Output = NlExpr;
+    fn div(self, rhs: T) -> NlExpr {
+        NlExpr::op(opcode::DIVIDE, vec![self, rhs.into()])
+    }
+}
+
+impl Neg for NlExpr {
+    type Output = NlExpr;
+    fn neg(self) -> NlExpr {
+        NlExpr::op(opcode::UMINUS, vec![self])
+    }
+}
+
+macro_rules! impl_f64_lhs_ops {
+    ($($trait:ident $method:ident),+) => {
+        $(
+            impl $trait<NlExpr> for f64 {
+                type Output = NlExpr;
+                fn $method(self, rhs: NlExpr) -> NlExpr {
+                    NlExpr::constant(self).$method(rhs)
+                }
+            }
+        )+
+    };
+}
+
+impl_f64_lhs_ops!(Add add, Sub sub, Mul mul, Div div);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+    extern crate self as grb;
+
+    #[test]
+    fn flatten_pre_order() -> Result<()> {
+        let mut m = Model::new("")?;
+        let x = add_ctsvar!(m)?;
+        let y = add_ctsvar!(m)?;
+        let expr = NlExpr::from(x) + NlExpr::from(y) * 2.0 + (1.0 - NlExpr::from(x)).sin();
+        let (ops, data, parent) = expr.flatten(&m)?;
+        use opcode::*;
+        assert_eq!(
+            ops,
+            [PLUS, VARIABLE, MULTIPLY, VARIABLE, CONSTANT, SIN, MINUS, CONSTANT, VARIABLE]
+        );
+        assert_eq!(data, [-1.0, 0.0, -1.0, 1.0, 2.0, -1.0, -1.0, 1.0, 0.0]);
+        assert_eq!(parent, [-1, 0, 0, 2, 2, 0, 5, 6, 6]);
+        Ok(())
+    }
+
+    #[test]
+    fn add_genconstr_nl() -> Result<()> {
+        let mut m = Model::new("")?;
+        m.set_param(param::OutputFlag, 0)?;
+        let x = add_ctsvar!(m, bounds: 0..2)?;
+        let y = add_ctsvar!(m, bounds: ..)?;
+        let z = add_ctsvar!(m, bounds: ..)?;
+        let gc1 = m.add_genconstr_nl("exp", y, NlExpr::from(x).exp())?;
+        let gc2 = m.add_genconstr_nl("pow", z, NlExpr::from(x).pow(3.0) - 2.0 * NlExpr::from(x))?;
+        m.update()?;
+        assert_eq!(m.get_genconstr_type(&gc1)?, crate::GenConstrType::Nl);
+        assert_eq!(m.get_genconstr_type(&gc2)?, crate::GenConstrType::Nl);
+
+        m.add_constr("fix", c!(x == 1.5))?;
+        m.optimize()?;
+        assert_eq!(m.status()?, Status::Optimal);
+        let y_val = m.get_obj_attr(attr::X, &y)?;
+        let z_val = m.get_obj_attr(attr::X, &z)?;
+        assert!((y_val - 1.5f64.exp()).abs() < 1e-4);
+        assert!((z_val - (1.5f64.powi(3) - 3.0)).abs() < 1e-4);
+        Ok(())
+    }
+}
@@ -103,6 +103,20 @@ impl ParamSet<String> for StrParam {
     }
 }
 
+/// Metadata about a numeric parameter, as returned by [`Env::get_int_param_info`]
+/// and [`Env::get_double_param_info`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ParamInfo<T> {
+    /// The current value of the parameter
+    pub value: T,
+    /// The smallest legal value of the parameter
+    pub min: T,
+    /// The largest legal value of the parameter
+    pub max: T,
+    /// The default value of the parameter
+    pub default: T,
+}
+
 /// Support for querying and setting dynamic/undocumented Gurobi parameters.
 ///
 /// Use an instance of this type to set or query parameters using the [`Model::get_param`](crate::Model::get_param)
@@ -247,4 +261,21 @@ mod tests {
         }
         Ok(())
     }
+
+    #[test]
+    fn string_parameter_round_trip() -> anyhow::Result<()> {
+        let mut model = crate::Model::new("test")?;
+        let param = Parameter::new("LogFile")?;
+        let logfile = std::env::temp_dir().join("grb_test_string_parameter.log");
+        let logfile = logfile.to_str().unwrap().to_string();
+
+        model.set_param(&param, logfile.clone())?;
+        assert_eq!(model.get_param::<_, String>(&param)?, logfile);
+        assert_eq!(model.get_param(crate::param::LogFile)?, logfile);
+
+        model.set_param(&param, String::new())?;
+        assert_eq!(model.get_param::<_, String>(&param)?, "");
+        std::fs::remove_file(&logfile).ok();
+        Ok(())
+    }
 }
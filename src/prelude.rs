@@ -17,6 +17,7 @@ pub use crate::{
     // ----------
     Env,
     GenConstr,
+    IisForce,
     Model,
     ModelObject,
     ModelSense,
@@ -28,6 +29,7 @@ pub use crate::{
     // constants
     VarType,
     INFINITY,
+    NEG_INFINITY,
     SOS,
 };
 
@@ -2,7 +2,11 @@ use crate::ffi;
 use crate::{Error, Result};
 use std::{
     ffi::{CStr, CString},
-    path::Path,
+    fs::OpenOptions,
+    io,
+    path::{Path, PathBuf},
+    sync::atomic::{AtomicU32, Ordering},
+    time::{SystemTime, UNIX_EPOCH},
 };
 
 /// Copy a raw C-string into a String
@@ -41,6 +45,90 @@ pub(crate) fn path_to_cstring<P: AsRef<Path>>(p: P) -> Result<CString> {
     CString::new(path).map_err(Error::NulError)
 }
 
+/// Convert an I/O error into an [`Error::Io`], adding the path of the file to the message.
+pub(crate) fn io_error(err: io::Error, path: &Path) -> Error {
+    Error::Io(io::Error::new(
+        err.kind(),
+        format!("{}: {err}", path.display()),
+    ))
+}
+
+/// An empty file in the system temporary directory, which is deleted when dropped.  Used to pass data to and from
+/// Gurobi routines which only work with files.
+pub(crate) struct TempFile {
+    path: PathBuf,
+}
+
+impl TempFile {
+    /// Create a new file whose name ends in `suffix`.  The file is created exclusively (never reusing an existing
+    /// file) and, on Unix, is only accessible by the current user.
+    pub(crate) fn new(suffix: &str) -> Result<TempFile> {
+        static COUNTER: AtomicU32 = AtomicU32::new(0);
+        let dir = std::env::temp_dir();
+        let mut options = OpenOptions::new();
+        options.write(true).create_new(true);
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::OpenOptionsExt;
+            options.mode(0o600);
+        }
+
+        loop {
+            let nanos = SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map_or(0, |d| d.subsec_nanos());
+            let name = format!(
+                "grb-{}-{}-{}{}",
+                std::process::id(),
+                COUNTER.fetch_add(1, Ordering::Relaxed),
+                nanos,
+                suffix
+            );
+            let path = dir.join(name);
+            match options.open(&path) {
+                Ok(_) => return Ok(TempFile { path }),
+                Err(e) if e.kind() == io::ErrorKind::AlreadyExists => continue,
+                Err(e) => return Err(io_error(e, &path)),
+            }
+        }
+    }
+
+    pub(crate) fn path(&self) -> &Path {
+        &self.path
+    }
+}
+
+impl Drop for TempFile {
+    fn drop(&mut self) {
+        std::fs::remove_file(&self.path).ok();
+    }
+}
+
+/// If `inds` is a block of consecutive indices in ascending order, return the first index.
+pub(crate) fn contiguous_start(inds: &[i32]) -> Option<i32> {
+    let first = *inds.first()?;
+    if inds
+        .iter()
+        .enumerate()
+        .all(|(k, &i)| i as i64 == first as i64 + k as i64)
+    {
+        Some(first)
+    } else {
+        None
+    }
+}
+
+#[test]
+fn contiguous_index_blocks() {
+    assert_eq!(contiguous_start(&[]), None);
+    assert_eq!(contiguous_start(&[3]), Some(3));
+    assert_eq!(contiguous_start(&[0, 1, 2, 3]), Some(0));
+    assert_eq!(contiguous_start(&[4, 5, 6]), Some(4));
+    assert_eq!(contiguous_start(&[0, 2, 3]), None);
+    assert_eq!(contiguous_start(&[2, 1, 0]), None);
+    assert_eq!(contiguous_start(&[1, 1]), None);
+}
+
 #[test]
 fn conversion_must_succeed() {
     use std::ffi::CString;
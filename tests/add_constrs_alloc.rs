@@ -0,0 +1,44 @@
+use grb::prelude::*;
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+struct CountingAlloc;
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for CountingAlloc {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAlloc = CountingAlloc;
+
+const N: usize = 10_000;
+
+#[test]
+fn unnamed_constraints_do_not_allocate_names() -> grb::Result<()> {
+    let mut m = Model::new("")?;
+    let x = add_ctsvar!(m)?;
+    let y = add_ctsvar!(m)?;
+    m.update()?;
+
+    let names = vec![""; N];
+    let constrs: Vec<_> = (0..N).map(|i| c!(x + y <= i)).collect();
+    let before = ALLOCATIONS.load(Ordering::Relaxed);
+    let added = m.add_constrs(names.iter().zip(constrs))?;
+    let allocations = ALLOCATIONS.load(Ordering::Relaxed) - before;
+    assert_eq!(added.len(), N);
+    // allocating a name per constraint would take at least N allocations
+    assert!(allocations < N / 10, "{} allocations", allocations);
+
+    m.update()?;
+    assert_eq!(m.num_constrs()?, N);
+    Ok(())
+}
@@ -0,0 +1,66 @@
+use grb::prelude::*;
+
+fn lp() -> grb::Result<Model> {
+    let mut env = Env::new("")?;
+    env.set(param::OutputFlag, 0)?;
+    let mut m = Model::with_env("lp", &env)?;
+    let x = add_ctsvar!(m, name: "x", bounds: 0..)?;
+    let y = add_ctsvar!(m, name: "y", bounds: 0..)?;
+    let z = add_ctsvar!(m, name: "z", bounds: 0..)?;
+    m.add_constr("c1", c!(x + 2 * y + 3 * z <= 10))?;
+    m.add_constr("c2", c!(3 * x + y + z <= 12))?;
+    m.add_constr("c3", c!(x + y + 2 * z <= 7))?;
+    m.set_objective(2 * x + 3 * y + z, Maximize)?;
+    m.set_param(param::Method, 0)?;
+    Ok(m)
+}
+
+#[test]
+fn write_and_read_basis() -> grb::Result<()> {
+    let path = std::env::temp_dir().join("grb_test_basis.bas");
+
+    let mut m = lp()?;
+    m.optimize()?;
+    assert!(m.get_attr(attr::IterCount)? > 0.0);
+    m.write_basis(&path)?;
+
+    let mut fresh = lp()?;
+    fresh.update()?;
+    fresh.read_basis(&path)?;
+    fresh.optimize()?;
+    assert_eq!(fresh.status()?, Status::Optimal);
+    assert_eq!(fresh.get_attr(attr::IterCount)?, 0.0);
+    assert_eq!(fresh.get_attr(attr::ObjVal)?, m.get_attr(attr::ObjVal)?);
+
+    std::fs::remove_file(&path).ok();
+    Ok(())
+}
+
+#[test]
+fn basis_suffix_enforced() -> grb::Result<()> {
+    let mut m = lp()?;
+    m.optimize()?;
+    let path = std::env::temp_dir().join("grb_test_basis.lp");
+    assert!(matches!(
+        m.write_basis(&path),
+        Err(grb::Error::InvalidArgument(_))
+    ));
+    assert!(matches!(
+        m.read_basis(&path),
+        Err(grb::Error::InvalidArgument(_))
+    ));
+    Ok(())
+}
+
+#[test]
+fn basis_rejected_for_mip() -> grb::Result<()> {
+    let mut m = lp()?;
+    add_intvar!(m, bounds: 0..10)?;
+    m.update()?;
+    let path = std::env::temp_dir().join("grb_test_basis_mip.bas");
+    assert!(matches!(
+        m.read_basis(&path),
+        Err(grb::Error::InvalidArgument(_))
+    ));
+    Ok(())
+}
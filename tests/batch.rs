@@ -0,0 +1,50 @@
+#![cfg(feature = "batch")]
+use grb::batch::{BatchId, BatchStatus};
+use grb::prelude::*;
+use std::time::{Duration, Instant};
+
+// Solving a batch requires a Cluster Manager or Instant Cloud license.  If the environment is set up to use one
+// (`CSManager` or `CloudAccessID` is set, usually in the license file), the batch must be solved, otherwise
+// submitting it must fail.
+#[test]
+fn batch() -> grb::Result<()> {
+    let mut env = Env::new("")?;
+    env.set(param::OutputFlag, 0)?;
+    let has_batch_license =
+        !env.get(param::CSManager)?.is_empty() || !env.get(param::CloudAccessID)?.is_empty();
+    if has_batch_license {
+        let mut batch_env = Env::empty()?;
+        batch_env.set(param::OutputFlag, 0)?;
+        batch_env.set(param::CSBatchMode, 1)?;
+        env = batch_env.start()?;
+    }
+    let mut m = Model::with_env("batch", &env)?;
+    let x = add_ctsvar!(m, bounds: 0..1)?;
+    m.set_objective(x, Maximize)?;
+
+    if has_batch_license {
+        let id = m.submit_batch()?;
+        let start = Instant::now();
+        let mut status = env.batch_status(&id)?;
+        while status == BatchStatus::Submitted && start.elapsed() < Duration::from_secs(300) {
+            std::thread::sleep(Duration::from_secs(1));
+            status = env.batch_status(&id)?;
+        }
+        assert_eq!(status, BatchStatus::Completed);
+        assert_eq!(m.batch_status(&id)?, BatchStatus::Completed);
+        assert!(env.batch_retrieve(&id)?.contains("SolutionInfo"));
+    } else {
+        assert!(matches!(m.submit_batch(), Err(grb::Error::FromAPI(_, _))));
+    }
+
+    let unknown = BatchId::new("no-such-batch");
+    assert!(matches!(
+        env.batch_status(&unknown),
+        Err(grb::Error::FromAPI(_, _))
+    ));
+    assert!(matches!(
+        m.batch_status(&unknown),
+        Err(grb::Error::FromAPI(_, _))
+    ));
+    Ok(())
+}
@@ -0,0 +1,31 @@
+use grb::callback::*;
+use grb::prelude::*;
+
+struct CountCalls(usize);
+
+impl Callback for CountCalls {
+    fn callback(&mut self, _w: Where) -> CbResult {
+        self.0 += 1;
+        Ok(())
+    }
+}
+
+#[test]
+fn callback_state_is_returned() -> grb::Result<()> {
+    let mut m = Model::new("")?;
+    m.set_param(param::OutputFlag, 0)?;
+    let x = add_intvar!(m, bounds: 0..10)?;
+    let y = add_intvar!(m, bounds: 0..10)?;
+    m.add_constr("c", c!(2 * x + 3 * y <= 17))?;
+    m.set_objective(x + 2 * y, Maximize)?;
+
+    let counter = m.optimize_with(CountCalls(0))?;
+    let calls = counter.0;
+    assert!(calls > 0);
+
+    // state accumulates across solves
+    m.reset(1)?;
+    let counter = m.optimize_with(counter)?;
+    assert!(counter.0 > calls);
+    Ok(())
+}
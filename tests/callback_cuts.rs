@@ -0,0 +1,47 @@
+use grb::callback::*;
+use grb::prelude::*;
+
+mod common;
+use common::*;
+
+struct AddCuts {
+    vars: Vec<Var>,
+    ubs: Vec<f64>,
+    added: bool,
+}
+
+impl Callback for AddCuts {
+    fn callback(&mut self, w: Where) -> CbResult {
+        if let Where::MIPNode(ctx) = w {
+            if ctx.status()? == Status::Optimal && !self.added {
+                // trivially valid cuts
+                let cuts = self.vars[..3]
+                    .iter()
+                    .zip(&self.ubs)
+                    .map(|(&x, &ub)| c!(x <= ub));
+                ctx.add_cuts(cuts)?;
+                self.added = true;
+                ctx.terminate();
+            }
+        }
+        Ok(())
+    }
+}
+
+#[test]
+fn add_multiple_cuts() -> anyhow::Result<()> {
+    let mut model = test_instance("mas76")?;
+    model.set_param(param::OutputFlag, 0)?;
+    model.set_param(param::PreCrush, 1)?;
+    let vars = model.get_vars()?.to_vec();
+    let ubs = model.get_obj_attr_batch(attr::UB, vars[..3].iter().copied())?;
+
+    let mut cb = AddCuts {
+        vars,
+        ubs,
+        added: false,
+    };
+    model.optimize_with_callback(&mut cb)?;
+    assert!(cb.added);
+    Ok(())
+}
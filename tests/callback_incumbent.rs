@@ -0,0 +1,59 @@
+use grb::callback::*;
+use grb::prelude::*;
+
+mod common;
+use common::*;
+
+#[test]
+fn incumbent_at_mipnode() -> anyhow::Result<()> {
+    let mut model = test_instance("mas76")?;
+    model.set_param(param::OutputFlag, 0)?;
+    let vars = model.get_vars()?.to_vec();
+    let obj = model.get_obj_attr_batch(attr::Obj, vars.iter().copied())?;
+    let obj_con = model.get_attr(attr::ObjCon)?;
+
+    let mut checked = 0;
+    let callback = |w: Where| {
+        if let Where::MIPNode(ctx) = w {
+            match ctx.get_incumbent(&vars)? {
+                None => assert_eq!(ctx.sol_cnt()?, 0),
+                Some(vals) => {
+                    let val: f64 = obj_con + vals.iter().zip(&obj).map(|(x, c)| x * c).sum::<f64>();
+                    let obj_best = ctx.obj_best()?;
+                    assert!((val - obj_best).abs() <= 1e-6 * obj_best.abs().max(1.0));
+                    checked += 1;
+                    if checked >= 10 {
+                        ctx.terminate();
+                    }
+                }
+            }
+        }
+        Ok(())
+    };
+    model.optimize_with_callback(&mut TrackIncumbent(callback))?;
+    assert!(checked > 0);
+    Ok(())
+}
+
+#[test]
+fn incumbent_not_tracked() -> anyhow::Result<()> {
+    let mut model = test_instance("mas76")?;
+    model.set_param(param::OutputFlag, 0)?;
+    let vars = model.get_vars()?.to_vec();
+
+    let mut checked = false;
+    let mut callback = |w: Where| {
+        if let Where::MIPNode(ctx) = w {
+            assert!(matches!(
+                ctx.get_incumbent(&vars),
+                Err(grb::Error::DataNotAvailable(_))
+            ));
+            checked = true;
+            ctx.terminate();
+        }
+        Ok(())
+    };
+    model.optimize_with_callback(&mut callback)?;
+    assert!(checked);
+    Ok(())
+}
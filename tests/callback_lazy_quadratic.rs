@@ -0,0 +1,32 @@
+use grb::callback::*;
+use grb::prelude::*;
+
+#[test]
+fn quadratic_lazy_constraint_not_supported() -> grb::Result<()> {
+    let mut m = Model::new("")?;
+    m.set_param(param::OutputFlag, 0)?;
+    m.set_param(param::LazyConstraints, 1)?;
+    let x = add_intvar!(m, bounds: 0..10)?;
+    let y = add_intvar!(m, bounds: 0..10)?;
+    m.set_objective(x + y, Maximize)?;
+
+    let mut results = Vec::new();
+    let mut callback = |w: Where| {
+        if let Where::MIPSol(ctx) = w {
+            results.push(ctx.add_lazy(c!(x * y <= 20)));
+            // quadratic terms which cancel out leave a linear constraint
+            results.push(ctx.add_lazy(c!(x * y + x + y <= 12 + x * y)));
+        }
+        Ok(())
+    };
+    m.optimize_with_callback(&mut callback)?;
+    assert_eq!(m.status()?, Status::Optimal);
+    assert_eq!(m.get_attr(attr::ObjVal)?, 12.0);
+
+    assert!(!results.is_empty());
+    for pair in results.chunks(2) {
+        assert!(matches!(pair[0], Err(grb::Error::NotYetSupported(_))));
+        assert!(pair[1].is_ok());
+    }
+    Ok(())
+}
@@ -0,0 +1,32 @@
+use grb::callback::*;
+use grb::prelude::*;
+
+#[test]
+fn model_vars_in_mipsol() -> grb::Result<()> {
+    let mut m = Model::new("")?;
+    m.set_param(param::OutputFlag, 0)?;
+    let x = add_intvar!(m, bounds: 0..10)?;
+    let y = add_intvar!(m, bounds: 0..10)?;
+    m.add_constr("c", c!(2 * x + 3 * y <= 17))?;
+    m.set_objective(x + 2 * y, Maximize)?;
+
+    let mut solutions = Vec::new();
+    let mut callback = |w: Where| {
+        if let Where::MIPSol(ctx) = w {
+            let vars = ctx.model_vars()?;
+            assert_eq!(vars, [x, y]);
+            let vals = ctx.get_solution(&vars)?;
+            solutions.push((vals, ctx.obj()?));
+        }
+        Ok(())
+    };
+    m.optimize_with_callback(&mut callback)?;
+
+    assert!(!solutions.is_empty());
+    for (vals, obj) in solutions {
+        assert_eq!(vals.len(), 2);
+        assert!(2.0 * vals[0] + 3.0 * vals[1] <= 17.0 + 1e-6);
+        assert!((vals[0] + 2.0 * vals[1] - obj).abs() < 1e-6);
+    }
+    Ok(())
+}
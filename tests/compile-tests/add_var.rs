@@ -9,5 +9,14 @@ fn main() -> grb::Result<()> {
     add_var!(m, Continuous, name: "y", obj: 1)?;
     add_var!(m, Binary, name: "x", bounds: ..100)?;
     add_var!(m, Binary, bounds: 0..30, name: "egg", obj: 4)?;
+    add_var!(m, Continuous, name: "z", bounds: 0..1, start: 0.5)?;
+    add_var!(m, Integer, start: 2, obj: 1)?;
+    add_var!(m, Integer, bounds: 0..=10)?;
+    add_var!(m, Continuous, bounds: ..=1.5)?;
+    add_ctsvar!(m, bounds: -5..5)?;
+    add_ctsvar!(m, bounds: -1e9..)?;
+    add_ctsvar!(m, bounds: ..-0.5)?;
+    let n: u8 = 3;
+    add_intvar!(m, bounds: -(n as i32)..n + 1)?;
     Ok(())
 }
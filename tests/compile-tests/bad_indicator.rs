@@ -0,0 +1,11 @@
+use grb::prelude::*;
+mod utils;
+
+fn main() -> grb::Result<()> {
+    create_model!(_g, m, x, y, z);
+    c!(x == 2 >> (y <= z));
+    c!(x >> (y <= z));
+    c!((x == 1) >> (y + z));
+    c!(x == 1 >> (y < z));
+    Ok(())
+}
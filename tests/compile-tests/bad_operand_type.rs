@@ -0,0 +1,11 @@
+use grb::prelude::*;
+mod utils;
+
+struct NotAnExpr;
+
+fn main() -> grb::Result<()> {
+    create_model!(_g, m, x);
+    let s = NotAnExpr;
+    c!(s >= x);
+    Ok(())
+}
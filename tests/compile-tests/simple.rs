@@ -11,5 +11,8 @@ fn main() -> grb::Result<()> {
     c!(x in ..1);
     c!(y - x in ..);
     c!(x in -2.3..1);
+
+    c!(x == 1 >> (y + z <= 1));
+    c!((x == 0) >> (y >= z));
     Ok(())
 }
@@ -0,0 +1,18 @@
+use grb::prelude::*;
+
+#[test]
+fn no_log_file_created() -> grb::Result<()> {
+    let dir = std::env::temp_dir().join("grb_test_env_no_log");
+    std::fs::create_dir_all(&dir).unwrap();
+    let _ = std::fs::remove_file(dir.join("gurobi.log"));
+    std::env::set_current_dir(&dir).unwrap();
+
+    let env = Env::new_no_log()?;
+    let mut m = Model::with_env("model", &env)?;
+    let x = add_ctsvar!(m, bounds: 0..1)?;
+    m.set_objective(x, Maximize)?;
+    m.optimize()?;
+
+    assert!(!dir.join("gurobi.log").exists());
+    Ok(())
+}
@@ -0,0 +1,21 @@
+use grb::callback::GapTerminator;
+use grb::prelude::*;
+
+mod common;
+use common::*;
+
+#[test]
+fn terminates_below_gap() -> anyhow::Result<()> {
+    let mut model = test_instance("mas76")?;
+    model.set_param(param::OutputFlag, 0)?;
+    model.set_param(param::MIPGap, 0.0)?;
+
+    let rel_gap = 0.2;
+    model.optimize_with_callback(&mut GapTerminator::new(rel_gap))?;
+
+    assert_eq!(model.status()?, Status::Interrupted);
+    let obj = model.get_attr(attr::ObjVal)?;
+    let bnd = model.get_attr(attr::ObjBound)?;
+    assert!((obj - bnd).abs() <= rel_gap * obj.abs());
+    Ok(())
+}
@@ -76,3 +76,24 @@ fn main() -> anyhow::Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn max_operands_from_vec_or_slice() -> anyhow::Result<()> {
+    let mut model = Model::new("")?;
+    model.set_param(param::OutputFlag, 0)?;
+    let x: Vec<_> = (0..3)
+        .map(|i| add_ctsvar!(model, bounds: i..i))
+        .collect::<Result<_, _>>()?;
+    let z1 = add_ctsvar!(model, obj: 1)?;
+    let z2 = add_ctsvar!(model, obj: 1)?;
+
+    let slice: &[Var] = &x;
+    model.add_genconstr_max("max_vec", z1, x.clone(), None)?;
+    model.add_genconstr_max("max_slice", z2, slice.iter().copied(), Some(5.0))?;
+
+    model.optimize()?;
+    assert_eq!(model.status()?, Status::Optimal);
+    assert_eq!(model.get_obj_attr(attr::X, &z1)?, 2.0);
+    assert_eq!(model.get_obj_attr(attr::X, &z2)?, 5.0);
+    Ok(())
+}
@@ -24,3 +24,40 @@ fn main() -> anyhow::Result<()> {
 
     Ok(())
 }
+
+#[derive(Default)]
+struct IisGuesses {
+    called: bool,
+    constr_guess: Option<u32>,
+    bound_guess: Option<u32>,
+}
+
+impl Callback for IisGuesses {
+    fn callback(&mut self, w: Where) -> CbResult {
+        if let Where::IIS(ctx) = w {
+            self.called = true;
+            self.constr_guess = ctx.constr_guess()?;
+            self.bound_guess = ctx.bound_guess()?;
+            assert!(ctx.constr_min()? <= ctx.constr_max()?);
+            assert!(ctx.bound_min()? <= ctx.bound_max()?);
+            ctx.terminate();
+        }
+        Ok(())
+    }
+}
+
+#[test]
+fn guesses() -> anyhow::Result<()> {
+    let mut model = test_instance("neos859080")?;
+    model.optimize()?;
+
+    let mut cb = IisGuesses::default();
+    model.compute_iis_with_callback(&mut cb)?;
+    assert!(cb.called);
+    // negative values (no guess available) are mapped to `None`
+    let nconstrs = model.get_attr(grb::attr::NumConstrs)? as u32;
+    let nvars = model.get_attr(grb::attr::NumVars)? as u32;
+    assert!(cb.constr_guess.into_iter().all(|n| n <= nconstrs));
+    assert!(cb.bound_guess.into_iter().all(|n| n <= 2 * nvars));
+    Ok(())
+}